@@ -9,6 +9,7 @@ pub mod dao_contract {
     use dao_psp22::dao_psp22::{ DaoPsp22Ref};
     use dao_psp34::dao_psp34::{ DaoPsp34Ref};
     use ink_prelude::string::{String};
+    use ink_env::call::{build_call, Call, ExecutionInput, Selector};
     use ink_prelude::{vec, vec::Vec};
     use ink_storage::traits::{PackedLayout, SpreadLayout};
     use ink_storage::traits::StorageLayout;
@@ -25,8 +26,28 @@ pub mod dao_contract {
         Psp34,
     }
 
-    #[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
-    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    /// the error of the external call.
+    /// * ink_env::Error is not SCALE encodable, so it is converted to this.
+    #[derive(
+        Debug, PartialEq, Eq, scale::Encode, scale::Decode, Clone, SpreadLayout, PackedLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(StorageLayout, scale_info::TypeInfo))]
+    pub enum CallError {
+        /// The return value of the callee can not be decoded.
+        Decode,
+        CalleeTrapped,
+        CalleeReverted,
+        KeyNotFound,
+        TransferFailed,
+        CodeNotFound,
+        NotCallable,
+        Unknown,
+    }
+
+    #[derive(
+        Debug, PartialEq, Eq, scale::Encode, scale::Decode, Clone, SpreadLayout, PackedLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(StorageLayout, scale_info::TypeInfo))]
     pub enum Error {
         /// The Token Does Not Exists.
         TheTokenDoesNotExist,
@@ -46,6 +67,8 @@ pub mod dao_contract {
         ThisLuggageDoesNotExists,
         OnlyMemberDoes,
         NotYourLuggage,
+        /// External Call is Failure
+        ExternalCallIsFailure(CallError),
     }
 
     pub type Result<T> = core::result::Result<T, Error>;
@@ -76,6 +99,33 @@ pub mod dao_contract {
         registar_timestamp: u64,
     }
 
+    /// wrapper to encode the SCALE encoded arguments as they are.
+    struct CallInput<'a>(&'a [u8]);
+
+    impl<'a> scale::Encode for CallInput<'a> {
+        fn encode_to<T: scale::Output + ?Sized>(&self, dest: &mut T) {
+            dest.write(self.0);
+        }
+    }
+
+    /// wrapper to take the SCALE encoded return value of the callee as it is.
+    struct CallOutput(Vec<u8>);
+
+    impl scale::Decode for CallOutput {
+        fn decode<I: scale::Input>(input: &mut I) -> core::result::Result<Self, scale::Error> {
+            let length = match input.remaining_len() {
+                Ok(Some(value)) => value,
+                Ok(None) => 0,
+                Err(e) => return Err(e),
+            };
+            let mut output = vec![0; length];
+            match input.read(&mut output) {
+                Ok(()) => Ok(CallOutput(output)),
+                Err(e) => Err(e),
+            }
+        }
+    }
+
     #[ink(storage)]
     pub struct DaoContract {
         /// token list id => token info
@@ -304,6 +354,40 @@ pub mod dao_contract {
             }
         }
 
+        /// call the message of the other contract.
+        /// * input: SCALE encoded arguments of the message.
+        /// * The SCALE encoded return value of the callee is returned as it is.
+        #[ink(message)]
+        pub fn execute_external_call(
+            &mut self,
+            callee: AccountId,
+            selector: [u8; 4],
+            input: Vec<u8>,
+            transferred_value: Balance,
+        ) -> Result<Vec<u8>> {
+            if !self._is_calling_from_dao_manager() {
+                return Err(Error::ThisFunctionCanBeCalledFromDaoManager);
+            }
+
+            match build_call::<ink_env::DefaultEnvironment>()
+                .call_type(
+                    Call::new()
+                        .callee(callee)
+                        .gas_limit(0)
+                        .transferred_value(transferred_value),
+                )
+                .exec_input(ExecutionInput::new(Selector::new(selector)).push_arg(CallInput(&input)))
+                .returns::<CallOutput>()
+                .fire()
+            {
+                Ok(output) => Ok(output.0),
+                Err(e) => {
+                    ink_env::debug_println!("     ########## external call error : {:?}", e);
+                    Err(Error::ExternalCallIsFailure(self._convert_call_error(e)))
+                }
+            }
+        }
+
         #[ink(message)]
         pub fn get_contract_balance(&self) -> Balance {
             self.env().balance()
@@ -359,6 +443,20 @@ pub mod dao_contract {
             self.env().caller() == self.dao_manager_account_id
         }
 
+        #[inline]
+        fn _convert_call_error(&self, error: ink_env::Error) -> CallError {
+            match error {
+                ink_env::Error::Decode(_e) => CallError::Decode,
+                ink_env::Error::CalleeTrapped => CallError::CalleeTrapped,
+                ink_env::Error::CalleeReverted => CallError::CalleeReverted,
+                ink_env::Error::KeyNotFound => CallError::KeyNotFound,
+                ink_env::Error::TransferFailed => CallError::TransferFailed,
+                ink_env::Error::CodeNotFound => CallError::CodeNotFound,
+                ink_env::Error::NotCallable => CallError::NotCallable,
+                _ => CallError::Unknown,
+            }
+        }
+
         #[inline]
         fn _convert_string_to_accountid(&self, account_str: &str) -> AccountId {
             let mut output = vec![0xFF; 35];
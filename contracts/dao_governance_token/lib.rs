@@ -6,8 +6,19 @@ pub use self::dao_governance_token::{DaoGovernanceToken, DaoGovernanceTokenRef};
 #[openbrush::contract]
 pub mod dao_governance_token {
     use ink_prelude::string::{String, ToString};
-    use openbrush::{contracts::psp22::extensions::metadata::*, traits::Storage};
+    use openbrush::{contracts::psp22::extensions::metadata::*, storage::Mapping, traits::Storage};
     use ink_storage::traits::SpreadAllocate;
+    use ink_storage::traits::StorageLayout;
+    use ink_storage::traits::{PackedLayout, SpreadLayout};
+
+    #[derive(
+        Default, Debug, Clone, scale::Encode, scale::Decode, SpreadLayout, PackedLayout, PartialEq,
+    )]
+    #[cfg_attr(feature = "std", derive(StorageLayout, scale_info::TypeInfo))]
+    pub struct Checkpoint {
+        block: BlockNumber,
+        balance: Balance,
+    }
 
     #[ink(storage)]
     #[derive(Default, SpreadAllocate, Storage)]
@@ -17,11 +28,35 @@ pub mod dao_governance_token {
         #[storage_field]
         metadata: metadata::Data,
         dao_account_id: AccountId,
+        /// ( account, checkpoint no ) => checkpoint
+        checkpoints: Mapping<(AccountId, u32), Checkpoint>,
+        /// account => count of checkpoints
+        checkpoint_counts: Mapping<AccountId, u32>,
     }
 
     impl PSP22 for DaoGovernanceToken {}
     impl PSP22Metadata for DaoGovernanceToken {}
 
+    impl psp22::Transfer for DaoGovernanceToken {
+        /// write checkpoints of both accounts on every transfer, mint and burn.
+        fn _after_token_transfer(
+            &mut self,
+            _from: Option<&AccountId>,
+            _to: Option<&AccountId>,
+            _amount: &Balance,
+        ) -> Result<(), PSP22Error> {
+            match _from {
+                Some(value) => self._write_checkpoint(*value),
+                None => (),
+            }
+            match _to {
+                Some(value) => self._write_checkpoint(*value),
+                None => (),
+            }
+            Ok(())
+        }
+    }
+
     impl DaoGovernanceToken {
         #[ink(constructor)]
         pub fn new(
@@ -65,6 +100,69 @@ pub mod dao_governance_token {
             }
         }
 
+        /// get circulating supply
+        /// * The tokens which the token contract itself holds are not distributed yet.
+        #[ink(message)]
+        pub fn circulating_supply(&self) -> Balance {
+            self.total_supply() - self.balance_of(self.env().account_id())
+        }
+
+        /// get the balance of the account at the end of the block.
+        #[ink(message)]
+        pub fn balance_of_at(&self, account: AccountId, block: BlockNumber) -> Balance {
+            let count = match self.checkpoint_counts.get(&account) {
+                Some(value) => value,
+                None => return 0,
+            };
+            // find the first checkpoint which is written after the block.
+            let mut low = 0;
+            let mut high = count;
+            while low < high {
+                let mid = (low + high) / 2;
+                match self.checkpoints.get(&(account, mid)) {
+                    Some(value) if value.block <= block => low = mid + 1,
+                    _ => high = mid,
+                }
+            }
+            if low == 0 {
+                return 0;
+            }
+            match self.checkpoints.get(&(account, low - 1)) {
+                Some(value) => value.balance,
+                None => 0,
+            }
+        }
+
+        /// get circulating supply at the end of the block.
+        #[ink(message)]
+        pub fn circulating_supply_at(&self, block: BlockNumber) -> Balance {
+            self.total_supply() - self.balance_of_at(self.env().account_id(), block)
+        }
+
+        /// write the current balance of the account as the checkpoint of the current block.
+        #[inline]
+        fn _write_checkpoint(&mut self, account: AccountId) {
+            let block = self.env().block_number();
+            let checkpoint = Checkpoint {
+                block: block,
+                balance: self.balance_of(account),
+            };
+            let count = match self.checkpoint_counts.get(&account) {
+                Some(value) => value,
+                None => 0,
+            };
+            if count > 0 {
+                match self.checkpoints.get(&(account, count - 1)) {
+                    Some(value) if value.block == block => {
+                        self.checkpoints.insert(&(account, count - 1), &checkpoint);
+                        return;
+                    }
+                    _ => (),
+                }
+            }
+            self.checkpoints.insert(&(account, count), &checkpoint);
+            self.checkpoint_counts.insert(&account, &(count + 1));
+        }
     }
 
     /// Unit tests in Rust are normally defined within such a `#[cfg(test)]`
@@ -93,5 +191,48 @@ pub mod dao_governance_token {
             dao_governance_token.flip();
             assert_eq!(dao_governance_token.get(), true);
         }
+
+        /// The balance before the first checkpoint is 0, and the checkpoint of the same block is overwritten.
+        #[ink::test]
+        fn balance_of_at_works() {
+            let accounts = ink_env::test::default_accounts::<ink_env::DefaultEnvironment>();
+            let mut dao_governance_token = DaoGovernanceToken::new(1000, None, None, 0, accounts.frank);
+            ink_env::test::advance_block::<ink_env::DefaultEnvironment>();
+            ink_env::test::advance_block::<ink_env::DefaultEnvironment>();
+            // block 2
+            assert_eq!(dao_governance_token._mint(accounts.bob, 100), Ok(()));
+            assert_eq!(dao_governance_token._mint(accounts.bob, 50), Ok(()));
+            assert_eq!(dao_governance_token.checkpoint_counts.get(&accounts.bob), Some(1));
+            ink_env::test::advance_block::<ink_env::DefaultEnvironment>();
+            // block 3
+            assert_eq!(dao_governance_token._mint(accounts.bob, 10), Ok(()));
+            assert_eq!(dao_governance_token.checkpoint_counts.get(&accounts.bob), Some(2));
+
+            assert_eq!(dao_governance_token.balance_of_at(accounts.bob, 0), 0);
+            assert_eq!(dao_governance_token.balance_of_at(accounts.bob, 1), 0);
+            assert_eq!(dao_governance_token.balance_of_at(accounts.bob, 2), 150);
+            assert_eq!(dao_governance_token.balance_of_at(accounts.bob, 3), 160);
+            assert_eq!(dao_governance_token.balance_of_at(accounts.bob, 10), 160);
+            assert_eq!(dao_governance_token.balance_of_at(accounts.charlie, 10), 0);
+        }
+
+        /// The binary search finds the right checkpoint among many checkpoints.
+        #[ink::test]
+        fn balance_of_at_finds_the_checkpoint() {
+            let accounts = ink_env::test::default_accounts::<ink_env::DefaultEnvironment>();
+            let mut dao_governance_token = DaoGovernanceToken::new(1000, None, None, 0, accounts.frank);
+            // a checkpoint of 1 token is written on every other block from block 1 to block 19.
+            for _i in 0..10 {
+                ink_env::test::advance_block::<ink_env::DefaultEnvironment>();
+                assert_eq!(dao_governance_token._mint(accounts.bob, 1), Ok(()));
+                ink_env::test::advance_block::<ink_env::DefaultEnvironment>();
+            }
+            assert_eq!(dao_governance_token.checkpoint_counts.get(&accounts.bob), Some(10));
+            assert_eq!(dao_governance_token.balance_of_at(accounts.bob, 0), 0);
+            for block in 1..20 {
+                assert_eq!(dao_governance_token.balance_of_at(accounts.bob, block), (block as Balance + 1) / 2);
+            }
+            assert_eq!(dao_governance_token.balance_of_at(accounts.bob, 100), 10);
+        }
     }
 }
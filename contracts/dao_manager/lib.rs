@@ -10,12 +10,15 @@ pub mod dao_manager {
     use ink_storage::traits::SpreadAllocate;
     use ink_storage::traits::StorageLayout;
     use ink_storage::traits::{PackedLayout, SpreadLayout};
-    use dao_contract::dao_contract::{DaoContractRef, TokenType};
+    use dao_contract::dao_contract::{DaoContractRef, TokenType, Error as DaoContractError};
     use openbrush::contracts::ownable::OwnableError;
     use openbrush::{contracts::ownable::*, modifiers, storage::Mapping, traits::Storage};
+    use rustc_hex::FromHex;
 
-    #[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
-    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    #[derive(
+        Debug, PartialEq, Eq, scale::Encode, scale::Decode, Clone, SpreadLayout, PackedLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(StorageLayout, scale_info::TypeInfo))]
     pub enum Error {
         TheDaoDoesNotExist,
         AddingTokenIsFailure,
@@ -29,6 +32,22 @@ pub mod dao_manager {
         TheMemberDoesNotExist,
         OnlyCallFromDeliverer,
         CallingChangeAprovalIsFailure,
+        /// The external call is failed with the error of the dao contract.
+        ExecutingExternalCallIsFailure(DaoContractError),
+        /// The account id in the csv data is invalid.
+        InvalidAccountId,
+        /// The amount in the csv data is invalid.
+        InvalidAmount,
+        /// The token type in the csv data is invalid.
+        InvalidTokenType,
+        /// The sales status in the csv data is invalid.
+        InvalidSalesStatus,
+        /// The list of receivers in the csv data is invalid.
+        InvalidReceiverList,
+        /// The selector in the csv data is invalid.
+        InvalidSelector,
+        /// The scale encoded arguments in the csv data are invalid.
+        InvalidCallArguments,
     }
 
     pub type Result<T> = core::result::Result<T, Error>;
@@ -153,14 +172,9 @@ pub mod dao_manager {
                 return Err(Error::TheDaoDoesNotExist);
             };
 
-            let data:Vec<&str> = csv_data.split(',').collect();
-            if data.len() != 2 {
-                return Err(Error::InvalidCsvData);
-            }
-            let token_account_id = self._convert_string_to_accountid(data[0]);
-            let token_type = match self._convert_str_2_token_type(data[1]) {
-                Some(value) => value,
-                None => return Err(Error::InvalidCsvData),
+            let (token_account_id, token_type) = match self._decode_add_dao_token_csv(csv_data) {
+                Ok(value) => value,
+                Err(e) => return Err(e),
             };
 
             let mut instance: DaoContractRef = ink_env::call::FromAccountId::from_account_id(dao_account_id);
@@ -182,12 +196,10 @@ pub mod dao_manager {
                 return Err(Error::TheDaoDoesNotExist);
             };
 
-            let data:Vec<&str> = csv_data.split(',').collect();
-            if data.len() != 2 {
-                return Err(Error::InvalidCsvData);
-            }
-            let token_account_id = self._convert_string_to_accountid(data[0]);
-            let is_start:bool = self._conver_str_2_bool(data[1]);
+            let (token_account_id, is_start) = match self._decode_change_token_sales_status_csv(csv_data) {
+                Ok(value) => value,
+                Err(e) => return Err(e),
+            };
 
             let mut instance: DaoContractRef = ink_env::call::FromAccountId::from_account_id(dao_account_id);
             match instance.change_token_sales_status(token_account_id, is_start) {
@@ -208,11 +220,10 @@ pub mod dao_manager {
                 return Err(Error::TheDaoDoesNotExist);
             };
 
-            let data:Vec<&str> = csv_data.split(',').collect();
-            if data.len() != 1 {
-                return Err(Error::InvalidCsvData);
-            }
-            let token_account_id = self._convert_string_to_accountid(data[0]);
+            let token_account_id = match self._decode_withdraw_token_proceeds_csv(csv_data) {
+                Ok(value) => value,
+                Err(e) => return Err(e),
+            };
 
             let mut instance: DaoContractRef = ink_env::call::FromAccountId::from_account_id(dao_account_id);
             match instance.withdraw_token_proceeds(token_account_id) {
@@ -233,12 +244,10 @@ pub mod dao_manager {
                 return Err(Error::TheDaoDoesNotExist);
             };
 
-            let data:Vec<&str> = csv_data.split(',').collect();
-            if data.len() != 2 {
-                return Err(Error::InvalidCsvData);
-            }
-            let token_account_id = self._convert_string_to_accountid(data[0]);
-            let list_of_reciver:String = data[1].to_string();
+            let (token_account_id, list_of_reciver) = match self._decode_distribute_governance_token_csv(csv_data) {
+                Ok(value) => value,
+                Err(e) => return Err(e),
+            };
 
             let mut instance: DaoContractRef = ink_env::call::FromAccountId::from_account_id(dao_account_id);
             match instance.distribute_governance_token(token_account_id, list_of_reciver) {
@@ -259,12 +268,10 @@ pub mod dao_manager {
                 return Err(Error::TheDaoDoesNotExist);
             };
 
-            let data:Vec<&str> = csv_data.split(',').collect();
-            if data.len() != 2 {
-                return Err(Error::InvalidCsvData);
-            }
-            let to = self._convert_string_to_accountid(data[0]);
-            let amount:u128 = data[1].parse().unwrap();
+            let (to, amount) = match self._decode_distribute_dao_treasury_csv(csv_data) {
+                Ok(value) => value,
+                Err(e) => return Err(e),
+            };
 
             let mut instance: DaoContractRef = ink_env::call::FromAccountId::from_account_id(dao_account_id);
             match instance.distribute_dao_treasury(to, amount) {
@@ -273,6 +280,220 @@ pub mod dao_manager {
             }
         }
 
+        /// execute external call
+        /// * This function can be called by proposal manager.
+        /// * csv_data: "callee_address,selector(hex),scale_encoded_arguments(hex),transferred_value"
+        /// * The SCALE encoded return value of the callee is returned, and the error of the call is passed through.
+        #[ink(message)]
+        pub fn execute_external_call(&mut self, dao_account_id:AccountId, csv_data:String) -> Result<Vec<u8>> {
+            if !self._is_calling_from_proposal_manager() {
+                return Err(Error::ThisFunctionCanBeCalledFromProposalManager);
+            }
+            if !self._dao_exists(dao_account_id){
+                return Err(Error::TheDaoDoesNotExist);
+            };
+
+            let (callee, selector, input, transferred_value) = match self._decode_execute_external_call_csv(csv_data) {
+                Ok(value) => value,
+                Err(e) => return Err(e),
+            };
+
+            let mut instance: DaoContractRef = ink_env::call::FromAccountId::from_account_id(dao_account_id);
+            match instance.execute_external_call(callee, selector, input, transferred_value) {
+                Ok(output) => Ok(output),
+                Err(e) => return Err(Error::ExecutingExternalCallIsFailure(e)),
+            }
+        }
+
+        /// validate csv data of add_dao_token without executing it.
+        #[ink(message)]
+        pub fn validate_add_dao_token_csv(&self, csv_data:String) -> Result<()> {
+            match self._decode_add_dao_token_csv(csv_data) {
+                Ok(_value) => Ok(()),
+                Err(e) => Err(e),
+            }
+        }
+
+        /// validate csv data of change_token_sales_status without executing it.
+        #[ink(message)]
+        pub fn validate_change_token_sales_status_csv(&self, csv_data:String) -> Result<()> {
+            match self._decode_change_token_sales_status_csv(csv_data) {
+                Ok(_value) => Ok(()),
+                Err(e) => Err(e),
+            }
+        }
+
+        /// validate csv data of withdraw_token_proceeds without executing it.
+        #[ink(message)]
+        pub fn validate_withdraw_token_proceeds_csv(&self, csv_data:String) -> Result<()> {
+            match self._decode_withdraw_token_proceeds_csv(csv_data) {
+                Ok(_value) => Ok(()),
+                Err(e) => Err(e),
+            }
+        }
+
+        /// validate csv data of distribute_governance_token without executing it.
+        #[ink(message)]
+        pub fn validate_distribute_governance_token_csv(&self, csv_data:String) -> Result<()> {
+            match self._decode_distribute_governance_token_csv(csv_data) {
+                Ok(_value) => Ok(()),
+                Err(e) => Err(e),
+            }
+        }
+
+        /// validate csv data of distribute_dao_treasury without executing it.
+        #[ink(message)]
+        pub fn validate_distribute_dao_treasury_csv(&self, csv_data:String) -> Result<()> {
+            match self._decode_distribute_dao_treasury_csv(csv_data) {
+                Ok(_value) => Ok(()),
+                Err(e) => Err(e),
+            }
+        }
+
+        /// get the amount which is paid by distribute_dao_treasury from its csv data.
+        #[ink(message)]
+        pub fn get_distribute_dao_treasury_amount(&self, csv_data:String) -> Result<Balance> {
+            match self._decode_distribute_dao_treasury_csv(csv_data) {
+                Ok((_to, amount)) => Ok(amount),
+                Err(e) => Err(e),
+            }
+        }
+
+        /// validate csv data of execute_external_call without executing it.
+        #[ink(message)]
+        pub fn validate_execute_external_call_csv(&self, csv_data:String) -> Result<()> {
+            match self._decode_execute_external_call_csv(csv_data) {
+                Ok(_value) => Ok(()),
+                Err(e) => Err(e),
+            }
+        }
+
+        /// * csv data: "token_address,token_type"
+        #[inline]
+        fn _decode_add_dao_token_csv(&self, csv_data:String) -> Result<(AccountId, TokenType)> {
+            let data:Vec<&str> = csv_data.split(',').collect();
+            if data.len() != 2 {
+                return Err(Error::InvalidCsvData);
+            }
+            let token_account_id = match self._convert_string_to_accountid(data[0]) {
+                Some(value) => value,
+                None => return Err(Error::InvalidAccountId),
+            };
+            let token_type = match self._convert_str_2_token_type(data[1]) {
+                Some(value) => value,
+                None => return Err(Error::InvalidTokenType),
+            };
+            Ok((token_account_id, token_type))
+        }
+
+        /// * csv data: "token_address,is_start("0" or "1")"
+        #[inline]
+        fn _decode_change_token_sales_status_csv(&self, csv_data:String) -> Result<(AccountId, bool)> {
+            let data:Vec<&str> = csv_data.split(',').collect();
+            if data.len() != 2 {
+                return Err(Error::InvalidCsvData);
+            }
+            let token_account_id = match self._convert_string_to_accountid(data[0]) {
+                Some(value) => value,
+                None => return Err(Error::InvalidAccountId),
+            };
+            let is_start = match self._conver_str_2_bool(data[1]) {
+                Some(value) => value,
+                None => return Err(Error::InvalidSalesStatus),
+            };
+            Ok((token_account_id, is_start))
+        }
+
+        /// * csv_data: "token_address"
+        #[inline]
+        fn _decode_withdraw_token_proceeds_csv(&self, csv_data:String) -> Result<AccountId> {
+            let data:Vec<&str> = csv_data.split(',').collect();
+            if data.len() != 1 {
+                return Err(Error::InvalidCsvData);
+            }
+            match self._convert_string_to_accountid(data[0]) {
+                Some(value) => Ok(value),
+                None => Err(Error::InvalidAccountId),
+            }
+        }
+
+        /// * csv_data : "token_address,list_of_reciever("reciver_data1(reciver_eoa#amount)?reciver_data2?...")"
+        #[inline]
+        fn _decode_distribute_governance_token_csv(&self, csv_data:String) -> Result<(AccountId, String)> {
+            let data:Vec<&str> = csv_data.split(',').collect();
+            if data.len() != 2 {
+                return Err(Error::InvalidCsvData);
+            }
+            let token_account_id = match self._convert_string_to_accountid(data[0]) {
+                Some(value) => value,
+                None => return Err(Error::InvalidAccountId),
+            };
+            for line in data[1].split('?') {
+                let part_data:Vec<&str> = line.split('#').collect();
+                if part_data.len() != 2 {
+                    return Err(Error::InvalidReceiverList);
+                }
+                match self._convert_string_to_accountid(part_data[0]) {
+                    Some(_value) => (),
+                    None => return Err(Error::InvalidAccountId),
+                };
+                match part_data[1].parse::<u128>() {
+                    Ok(_value) => (),
+                    Err(_e) => return Err(Error::InvalidAmount),
+                };
+            }
+            Ok((token_account_id, data[1].to_string()))
+        }
+
+        /// * csv_data: "reciver_address,amount"
+        #[inline]
+        fn _decode_distribute_dao_treasury_csv(&self, csv_data:String) -> Result<(AccountId, u128)> {
+            let data:Vec<&str> = csv_data.split(',').collect();
+            if data.len() != 2 {
+                return Err(Error::InvalidCsvData);
+            }
+            let to = match self._convert_string_to_accountid(data[0]) {
+                Some(value) => value,
+                None => return Err(Error::InvalidAccountId),
+            };
+            let amount:u128 = match data[1].parse() {
+                Ok(value) => value,
+                Err(_e) => return Err(Error::InvalidAmount),
+            };
+            Ok((to, amount))
+        }
+
+        /// * csv_data: "callee_address,selector(hex),scale_encoded_arguments(hex),transferred_value"
+        #[inline]
+        fn _decode_execute_external_call_csv(&self, csv_data:String) -> Result<(AccountId, [u8; 4], Vec<u8>, u128)> {
+            let data:Vec<&str> = csv_data.split(',').collect();
+            if data.len() != 4 {
+                return Err(Error::InvalidCsvData);
+            }
+            let callee = match self._convert_string_to_accountid(data[0]) {
+                Some(value) => value,
+                None => return Err(Error::InvalidAccountId),
+            };
+            let selector_vec:Vec<u8> = match data[1].trim_start_matches("0x").from_hex() {
+                Ok(value) => value,
+                Err(_e) => return Err(Error::InvalidSelector),
+            };
+            if selector_vec.len() != 4 {
+                return Err(Error::InvalidSelector);
+            }
+            let mut selector = [0; 4];
+            selector.copy_from_slice(&selector_vec);
+            let input:Vec<u8> = match data[2].trim_start_matches("0x").from_hex() {
+                Ok(value) => value,
+                Err(_e) => return Err(Error::InvalidCallArguments),
+            };
+            let transferred_value:u128 = match data[3].parse() {
+                Ok(value) => value,
+                Err(_e) => return Err(Error::InvalidAmount),
+            };
+            Ok((callee, selector, input, transferred_value))
+        }
+
         #[inline]
         fn _can_call_only_deliverer(&self) -> bool {
             let caller = self.env().caller();
@@ -296,34 +517,37 @@ pub mod dao_manager {
         }
 
         #[inline]
-        fn _convert_string_to_accountid(&self, account_str: &str) -> AccountId {
+        fn _convert_string_to_accountid(&self, account_str: &str) -> Option<AccountId> {
             let mut output = vec![0xFF; 35];
-            bs58::decode(account_str).into(&mut output).unwrap();
+            // the ss58 address is 35 bytes: prefix ( 1 ) + public key ( 32 ) + checksum ( 2 ).
+            match bs58::decode(account_str).into(&mut output) {
+                Ok(35) => (),
+                _ => return None,
+            };
             let cut_address_vec: Vec<_> = output.drain(1..33).collect();
             let mut array = [0; 32];
             let bytes = &cut_address_vec[..array.len()];
             array.copy_from_slice(bytes);
             let account_id: AccountId = array.into();
-            account_id
+            Some(account_id)
         }
 
         #[inline]
         fn _convert_str_2_token_type(&self, type_str:&str) -> Option<TokenType> {
-            let convert_type:u8= type_str.parse().unwrap();
-            match convert_type {
-                2 => Some(TokenType::GovernanceToken),
-                0 => Some(TokenType::Psp22),
-                1 => Some(TokenType::Psp34),
+            match type_str {
+                "2" => Some(TokenType::GovernanceToken),
+                "0" => Some(TokenType::Psp22),
+                "1" => Some(TokenType::Psp34),
                 _ => None,
             }
         }
 
         #[inline]
-        fn _conver_str_2_bool(&self, bool_str:&str) -> bool {
-            let convert_bool:u8= bool_str.parse().unwrap();
-            match convert_bool {
-                0 => true,
-                _ => false,
+        fn _conver_str_2_bool(&self, bool_str:&str) -> Option<bool> {
+            match bool_str {
+                "0" => Some(true),
+                "1" => Some(false),
+                _ => None,
             }
         }
 
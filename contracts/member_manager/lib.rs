@@ -30,6 +30,18 @@ pub mod member_manager {
         address_of_real_world: String
     }
 
+    #[derive(
+        Default, Debug, Clone, scale::Encode, scale::Decode, SpreadLayout, PackedLayout, PartialEq,
+    )]
+    #[cfg_attr(feature = "std", derive(StorageLayout, scale_info::TypeInfo))]
+    pub struct Tenure {
+        start_time: u64,
+        term_length: u64,
+    }
+
+    /// default term length of electoral commissioners. ( 90 days in milliseconds )
+    pub const TERM_LENGTH: u64 = 90 * 24 * 60 * 60 * 1000;
+
     #[ink(storage)]
     // #[derive(SpreadAllocate)]
     #[derive(SpreadAllocate, Storage, Default)]
@@ -48,12 +60,29 @@ pub mod member_manager {
         next_memger_ids: Mapping<AccountId, u16>,
         // DAO address => commissioner_no
         next_commissioner_nos: Mapping<AccountId, u16>,
+        // DAO address => membership epoch ( incremented whenever a member is added or deleted )
+        membership_epochs: Mapping<AccountId, u32>,
+        // ( DAO address , EOA Address ) => membership epoch when the member joined
+        joined_epochs: Mapping<(AccountId, AccountId), u32>,
+        // ( DAO address , EOA Address ) => tenure of the electoral commissioner
+        tenures: Mapping<(AccountId, AccountId), Tenure>,
+        // DAO address => term length of electoral commissioners
+        term_lengths: Mapping<AccountId, u64>,
     }
 
     impl Ownable for MemberManager {}
 
-    #[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
-    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    impl MemberInfo {
+        /// get the member address.
+        pub fn member_address(&self) -> AccountId {
+            self.member_address
+        }
+    }
+
+    #[derive(
+        Debug, PartialEq, Eq, scale::Encode, scale::Decode, Clone, SpreadLayout, PackedLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(StorageLayout, scale_info::TypeInfo))]
     pub enum Error {
         /// Not first member
         NotFirstMember,
@@ -81,6 +110,12 @@ pub mod member_manager {
         PossibleBug,
         /// The Name is already used.
         TheNameIsAlreadyUsed,
+        /// The account id in the csv data is invalid.
+        InvalidAccountId,
+        /// The token id in the csv data is invalid.
+        InvalidTokenId,
+        /// The target is not an electoral commissioner.
+        NotElectoralCommissioner,
     }
 
     pub type ResultTransaction<T> = core::result::Result<T, Error>;
@@ -127,7 +162,7 @@ pub mod member_manager {
         }
 
         /// add a member
-        /// * csv_data: name, member_address, 0, 0
+        /// * csv_data: name, member_address, 0, 0, address_of_real_world
         #[ink(message)]
         pub fn add_member(
             &mut self,
@@ -140,10 +175,10 @@ pub mod member_manager {
             }
 
             let member_info = match self.inline_convert_csv_2_memberinfo(csv_data.clone()) {
-                Some(value) => value,
-                None => {
+                Ok(value) => value,
+                Err(e) => {
                     ink_env::debug_println!("########## CsvConvertFailure Error. csv_data:{:?}",csv_data);
-                    return Err(Error::CsvConvertFailure);
+                    return Err(e);
                 },
             };
 
@@ -173,6 +208,15 @@ pub mod member_manager {
             Ok(())
         }
 
+        /// validate csv data of add_member without adding the member.
+        #[ink(message)]
+        pub fn validate_add_member_csv(&self, csv_data: String) -> ResultTransaction<()> {
+            match self.inline_convert_csv_2_memberinfo(csv_data) {
+                Ok(_value) => Ok(()),
+                Err(e) => Err(e),
+            }
+        }
+
         #[ink(message)]
         pub fn get_member_info(&self, dao_account_id:AccountId, target_account_id:AccountId) -> Option<MemberInfo> {
             self.member_infoes.get(&(dao_account_id, target_account_id))
@@ -186,12 +230,23 @@ pub mod member_manager {
                 ink_env::debug_println!("########################### OnlyFromProposalManagerAddress Error.");
                 return Err(Error::OnlyFromProposalManagerAddress);
             }
-            let _array: Vec<&str> = _csv_data.split(',').collect();
-            if _array.len() > 1 {
-                ink_env::debug_println!("InvalidDeleteMemberCount Error.");
-                return Err(Error::InvalidDeleteMemberCount);
+            let account_vec = match self.inline_convert_csv_2_delete_member(_csv_data) {
+                Ok(value) => value,
+                Err(e) => {
+                    ink_env::debug_println!("InvalidDeleteMemberCount Error.");
+                    return Err(e);
+                },
+            };
+            self.inline_delete_member(_dao_address, account_vec[0])
+        }
+
+        /// validate csv data of delete_member without deleting the member.
+        #[ink(message)]
+        pub fn validate_delete_member_csv(&self, _csv_data: String) -> ResultTransaction<()> {
+            match self.inline_convert_csv_2_delete_member(_csv_data) {
+                Ok(_value) => Ok(()),
+                Err(e) => Err(e),
             }
-            self.inline_delete_member(_dao_address, self.convert_string_to_accountid(_array[0]))
         }
 
         /// inline delete the member.
@@ -228,11 +283,14 @@ pub mod member_manager {
                     };
                 if electoral_commissioner_address == member_info.member_address {
                     self.electoral_commissioner.remove(&(dao_address, i));
+                    self.tenures.remove(&(dao_address, member_address));
                 }
             }
             self.member_infoes_from_id
                 .remove(&(dao_address, member_info.member_id));
             self.member_infoes.remove(&(dao_address, member_address));
+            self.joined_epochs.remove(&(dao_address, member_address));
+            self.inline_increment_membership_epoch(dao_address);
             Ok(())
         }
 
@@ -249,17 +307,105 @@ pub mod member_manager {
                 return Err(Error::OnlyFromProposalManagerAddress);
             }
 
-            let _array: Vec<&str> = _csv_data.split(',').collect();
-            if _array.len() > self.get_member_list(_dao_address).len() {
-                ink_env::debug_println!("########################### InvalidElectoralCommissionerCount Error. array:{:?}  member:{:?}",_array.len(),self.get_member_list(_dao_address).len());
+            let account_vec = match self.inline_convert_csv_2_electoral_commissioners(_dao_address, _csv_data) {
+                Ok(value) => value,
+                Err(e) => {
+                    ink_env::debug_println!("########################### InvalidElectoralCommissionerCount Error.");
+                    return Err(e);
+                },
+            };
+
+            self.inline_change_electoral_commissioner(_dao_address, account_vec)
+        }
+
+        /// elect electoral commissioners
+        /// * This function can be called by proposal manager when the election is finished.
+        #[ink(message)]
+        pub fn elect_electoral_commissioners(
+            &mut self,
+            _dao_address: AccountId,
+            _candidates: Vec<AccountId>,
+        ) -> ResultTransaction<()> {
+            if self.modifier_only_call_from_proposal_manager() == false {
+                ink_env::debug_println!("########################### OnlyFromProposalManagerAddress Error.");
+                return Err(Error::OnlyFromProposalManagerAddress);
+            }
+            if _candidates.len() == 0 || _candidates.len() > self.get_member_list(_dao_address).len() {
                 return Err(Error::InvalidElectoralCommissionerCount);
+            }
+            self.inline_change_electoral_commissioner(_dao_address, _candidates)
+        }
+
+        /// recall electoral commissioners during their term.
+        /// * This function can be called by proposal manager.
+        /// * Only the named electoral commissioners are dismissed and the others remain.
+        /// * csv_data: eoa_address, eoa_address,....
+        #[ink(message)]
+        pub fn recall_electoral_commissioner(
+            &mut self,
+            _dao_address: AccountId,
+            _csv_data: String,
+        ) -> ResultTransaction<()> {
+            if self.modifier_only_call_from_proposal_manager() == false {
+                ink_env::debug_println!("########################### OnlyFromProposalManagerAddress Error.");
+                return Err(Error::OnlyFromProposalManagerAddress);
+            }
+            let account_vec = match self.inline_convert_csv_2_recalled_commissioners(_dao_address, _csv_data) {
+                Ok(value) => value,
+                Err(e) => return Err(e),
             };
-            let mut account_vec: Vec<AccountId> = Vec::new();
-            for account in _array {
-                account_vec.push(self.convert_string_to_accountid(account));
+
+            let next_commissioner_no = match self.next_commissioner_nos.get(&_dao_address) {
+                Some(value) => value,
+                None => return Err(Error::PossibleBug),
+            };
+            for i in 0..next_commissioner_no {
+                let member_address = match self.electoral_commissioner.get(&(_dao_address, i)) {
+                    Some(value) => value,
+                    None => continue,
+                };
+                if !account_vec.contains(&member_address) {
+                    continue;
+                }
+                let mut member_info = match self.member_infoes.get(&(_dao_address, member_address)) {
+                    Some(value) => value,
+                    None => return Err(Error::ElectoralCommissionerDataMismatch),
+                };
+                member_info.is_electoral_commissioner = false;
+                self.member_infoes
+                    .insert(&(_dao_address, member_address), &member_info.clone());
+                self.member_infoes_from_id
+                    .insert(&(_dao_address, member_info.member_id), &member_info.clone());
+                self.electoral_commissioner.remove(&(_dao_address, i));
+                self.tenures.remove(&(_dao_address, member_address));
             }
+            Ok(())
+        }
 
-            self.inline_change_electoral_commissioner(_dao_address, account_vec)
+        /// validate csv data of recall_electoral_commissioner without recalling electoral commissioners.
+        #[ink(message)]
+        pub fn validate_recall_electoral_commissioner_csv(
+            &self,
+            _dao_address: AccountId,
+            _csv_data: String,
+        ) -> ResultTransaction<()> {
+            match self.inline_convert_csv_2_recalled_commissioners(_dao_address, _csv_data) {
+                Ok(_value) => Ok(()),
+                Err(e) => Err(e),
+            }
+        }
+
+        /// validate csv data of change_electoral_commissioner without changing electoral commissioners.
+        #[ink(message)]
+        pub fn validate_change_electoral_commissioner_csv(
+            &self,
+            _dao_address: AccountId,
+            _csv_data: String,
+        ) -> ResultTransaction<()> {
+            match self.inline_convert_csv_2_electoral_commissioners(_dao_address, _csv_data) {
+                Ok(_value) => Ok(()),
+                Err(e) => Err(e),
+            }
         }
 
         /// get member list.
@@ -327,7 +473,7 @@ pub mod member_manager {
                             return true;
                         }
                     }
-                    None => return false,
+                    None => continue,
                 };
             }
             false
@@ -342,6 +488,81 @@ pub mod member_manager {
             }
         }
 
+        /// modifier of only member who joined the dao at or before the membership epoch.
+        #[ink(message)]
+        pub fn modifier_only_member_at_epoch(
+            &self,
+            caller: AccountId,
+            _dao_address: AccountId,
+            _epoch: u32,
+        ) -> bool {
+            if !self.modifier_only_member(caller, _dao_address) {
+                return false;
+            }
+            match self.joined_epochs.get(&(_dao_address, caller)) {
+                Some(value) => value <= _epoch,
+                None => true,
+            }
+        }
+
+        /// set the term length of electoral commissioners.
+        /// * This function can be called by proposal manager.
+        /// * The new term length is applied to electoral commissioners appointed after this change.
+        #[ink(message)]
+        pub fn set_term_length(&mut self, _dao_address: AccountId, _term_length: u64) -> ResultTransaction<()> {
+            if self.modifier_only_call_from_proposal_manager() == false {
+                return Err(Error::OnlyFromProposalManagerAddress);
+            }
+            self.term_lengths.insert(&_dao_address, &_term_length);
+            Ok(())
+        }
+
+        /// get the term length of electoral commissioners.
+        #[ink(message)]
+        pub fn get_term_length(&self, _dao_address: AccountId) -> u64 {
+            match self.term_lengths.get(&_dao_address) {
+                Some(value) => value,
+                None => TERM_LENGTH,
+            }
+        }
+
+        /// get the tenure of the electoral commissioner.
+        #[ink(message)]
+        pub fn get_tenure(&self, _dao_address: AccountId, _electoral_commissioner: AccountId) -> Option<Tenure> {
+            self.tenures.get(&(_dao_address, _electoral_commissioner))
+        }
+
+        /// check whether the tenure of electoral commissioners has expired.
+        /// * true if there is no electoral commissioner or the term of any electoral commissioner has passed.
+        #[ink(message)]
+        pub fn is_tenure_expired(&self, _dao_address: AccountId) -> bool {
+            let list = self.get_electoral_commissioner_list(_dao_address);
+            if list.len() == 0 {
+                return true;
+            }
+            let now = self.env().block_timestamp();
+            for member_info in list {
+                match self.tenures.get(&(_dao_address, member_info.member_address)) {
+                    Some(value) => {
+                        if value.start_time + value.term_length <= now {
+                            return true;
+                        }
+                    }
+                    None => return true,
+                }
+            }
+            false
+        }
+
+        /// get the current membership epoch of the dao.
+        #[ink(message)]
+        pub fn get_membership_epoch(&self, _dao_address: AccountId) -> u32 {
+            match self.membership_epochs.get(&_dao_address) {
+                Some(value) => value,
+                None => 0,
+            }
+        }
+
         /// modifier of only electoral commissioner
         #[ink(message)]
         pub fn modifier_only_electoral_commissioner(
@@ -360,7 +581,7 @@ pub mod member_manager {
                             return true;
                         }
                     }
-                    None => return false,
+                    None => continue,
                 };
             }
             false
@@ -409,6 +630,7 @@ pub mod member_manager {
                 member_info.is_electoral_commissioner = true;
                 self.member_infoes
                     .insert(&(dao_address, account), &member_info.clone());
+                self.inline_start_tenure(dao_address, account);
                 self.member_infoes_from_id
                     .insert(&(dao_address, member_info.member_id), &member_info.clone());
             }
@@ -426,9 +648,10 @@ pub mod member_manager {
                 None => return Err(Error::PossibleBug),
             };
             for i in 0..next_commissioner_no {
+                // the slot of the deleted or recalled electoral commissioner is empty.
                 let member_address = match self.electoral_commissioner.get(&(dao_address, i)) {
                     Some(value) => value,
-                    None => return Err(Error::ElectoralCommissionerDataMismatch),
+                    None => continue,
                 };
                 let mut member_info = match self.member_infoes.get(&(dao_address, member_address))
                 {
@@ -444,41 +667,121 @@ pub mod member_manager {
                     .insert(&(dao_address, member_info.member_id), &member_info.clone());
 
                 self.electoral_commissioner.remove(&(dao_address, i));
+                self.tenures.remove(&(dao_address, member_address));
             }
             self.next_commissioner_nos.insert(&dao_address,&0);
             Ok(())
         }
 
         #[inline]
-        fn convert_string_to_accountid(&self, account_str: &str) -> AccountId {
+        fn convert_string_to_accountid(&self, account_str: &str) -> Option<AccountId> {
             let mut output = vec![0xFF; 35];
-            bs58::decode(account_str).into(&mut output).unwrap();
+            // the ss58 address is 35 bytes: prefix ( 1 ) + public key ( 32 ) + checksum ( 2 ).
+            match bs58::decode(account_str).into(&mut output) {
+                Ok(35) => (),
+                _ => return None,
+            };
             let cut_address_vec: Vec<_> = output.drain(1..33).collect();
             let mut array = [0; 32];
             let bytes = &cut_address_vec[..array.len()];
             array.copy_from_slice(bytes);
             let account_id: AccountId = array.into();
-            account_id
+            Some(account_id)
         }
 
+        /// * csv_data: name, member_address, member_id, token_id, address_of_real_world
         #[inline]
-        fn inline_convert_csv_2_memberinfo(&self, csv_data: String) -> Option<MemberInfo> {
+        fn inline_convert_csv_2_memberinfo(&self, csv_data: String) -> ResultTransaction<MemberInfo> {
             let array: Vec<&str> = csv_data.split(',').collect();
-            if array.len() != 5 {
-                return None;
+            if array.len() != 5 || array[0].len() == 0 {
+                return Err(Error::CsvConvertFailure);
+            };
+            let member_address = match self.convert_string_to_accountid(array[1]) {
+                Some(value) => value,
+                None => return Err(Error::InvalidAccountId),
+            };
+            let member_id = match array[2].parse::<u16>() {
+                Ok(value) => value,
+                Err(_e) => return Err(Error::CsvConvertFailure),
+            };
+            let token_id = match array[3].parse::<u16>() {
+                Ok(value) => value,
+                Err(_e) => return Err(Error::InvalidTokenId),
             };
 
-            Some(MemberInfo {
+            Ok(MemberInfo {
                 name: array[0].to_string(),
-                member_address: self.convert_string_to_accountid(array[1]),
-                member_id: array[2].parse::<u16>().unwrap(),
-                token_id: array[3].parse::<u16>().unwrap(),
+                member_address: member_address,
+                member_id: member_id,
+                token_id: token_id,
                 is_electoral_commissioner: false,
-                address_of_real_world: array[5].to_string(),
-
+                address_of_real_world: array[4].to_string(),
             })
         }
 
+        /// * csv_data: eoa_address
+        #[inline]
+        fn inline_convert_csv_2_delete_member(&self, csv_data: String) -> ResultTransaction<Vec<AccountId>> {
+            let array: Vec<&str> = csv_data.split(',').collect();
+            if array.len() != 1 {
+                return Err(Error::InvalidDeleteMemberCount);
+            }
+            match self.convert_string_to_accountid(array[0]) {
+                Some(value) => Ok(vec![value]),
+                None => Err(Error::InvalidAccountId),
+            }
+        }
+
+        /// * csv_data: eoa_address, eoa_address,.... ( each address has to be a member )
+        #[inline]
+        fn inline_convert_csv_2_electoral_commissioners(
+            &self,
+            dao_address: AccountId,
+            csv_data: String,
+        ) -> ResultTransaction<Vec<AccountId>> {
+            let array: Vec<&str> = csv_data.split(',').collect();
+            if array.len() > self.get_member_list(dao_address).len() {
+                return Err(Error::InvalidElectoralCommissionerCount);
+            };
+            let mut account_vec: Vec<AccountId> = Vec::new();
+            for account in array {
+                let member_address = match self.convert_string_to_accountid(account) {
+                    Some(value) => value,
+                    None => return Err(Error::InvalidAccountId),
+                };
+                match self.member_infoes.get(&(dao_address, member_address)) {
+                    Some(_value) => account_vec.push(member_address),
+                    None => return Err(Error::MemberDoesNotExist),
+                };
+            }
+            Ok(account_vec)
+        }
+
+        /// * csv_data: eoa_address, eoa_address,.... ( each address has to be an electoral commissioner )
+        #[inline]
+        fn inline_convert_csv_2_recalled_commissioners(
+            &self,
+            dao_address: AccountId,
+            csv_data: String,
+        ) -> ResultTransaction<Vec<AccountId>> {
+            let mut account_vec: Vec<AccountId> = Vec::new();
+            for account in csv_data.split(',') {
+                let member_address = match self.convert_string_to_accountid(account) {
+                    Some(value) => value,
+                    None => return Err(Error::InvalidAccountId),
+                };
+                match self.member_infoes.get(&(dao_address, member_address)) {
+                    Some(value) if value.is_electoral_commissioner => (),
+                    _ => return Err(Error::NotElectoralCommissioner),
+                };
+                if account_vec.contains(&member_address) {
+                    return Err(Error::InvalidElectoralCommissionerCount);
+                }
+                account_vec.push(member_address);
+            }
+            Ok(account_vec)
+        }
+
         #[inline]
         fn inline_add_member(
             &mut self,
@@ -508,6 +811,8 @@ pub mod member_manager {
                 .insert(&(dao_address, next_member_id), &member_info.clone());
             next_member_id = next_member_id + 1;
             self.next_memger_ids.insert(&dao_address, &next_member_id);
+            let epoch = self.inline_increment_membership_epoch(dao_address);
+            self.joined_epochs.insert(&(dao_address, member_address), &epoch);
 
             if is_electoral_commissioner {
                 let mut next_commissioner_no = match self.next_commissioner_nos.get(&dao_address) {
@@ -518,9 +823,28 @@ pub mod member_manager {
                     .insert(&(dao_address, next_commissioner_no), &member_address);
                 next_commissioner_no = next_commissioner_no + 1;
                 self.next_commissioner_nos.insert(&dao_address,&next_commissioner_no);
+                self.inline_start_tenure(dao_address, member_address);
             }
         }
 
+        /// start the tenure of the electoral commissioner from now.
+        #[inline]
+        fn inline_start_tenure(&mut self, dao_address: AccountId, member_address: AccountId) {
+            let tenure = Tenure {
+                start_time: self.env().block_timestamp(),
+                term_length: self.get_term_length(dao_address),
+            };
+            self.tenures.insert(&(dao_address, member_address), &tenure);
+        }
+
+        /// increment the membership epoch and return the new epoch.
+        #[inline]
+        fn inline_increment_membership_epoch(&mut self, dao_address: AccountId) -> u32 {
+            let epoch = self.get_membership_epoch(dao_address) + 1;
+            self.membership_epochs.insert(&dao_address, &epoch);
+            epoch
+        }
+
         #[inline]
         fn modifier_only_call_from_proposal_manager(&self) -> bool {
             self.env().caller() == self.proposal_manager_address
@@ -9,15 +9,22 @@ pub use self::proposal_manager::{ProposalManager, ProposalManagerRef};
 #[openbrush::contract]
 pub mod proposal_manager {
     use ink_prelude::string::{String};
-    use ink_prelude::vec::Vec;
+    use ink_prelude::{vec, vec::Vec};
     use ink_storage::traits::StorageLayout;
     use ink_storage::traits::{PackedLayout, SpreadLayout};
     use member_manager::MemberManagerRef;
+    use member_manager::member_manager::Error as MemberManagerError;
+    use member_manager::member_manager::TERM_LENGTH;
     use dao_manager::DaoManagerRef;
+    use dao_manager::dao_manager::Error as DaoManagerError;
+    use dao_governance_token::DaoGovernanceTokenRef;
+    use ink_env::call::{build_call, Call, ExecutionInput, Selector};
     use openbrush::{storage::Mapping};
 
-    #[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
-    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    #[derive(
+        Debug, PartialEq, Eq, scale::Encode, scale::Decode, Clone, SpreadLayout, PackedLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(StorageLayout, scale_info::TypeInfo))]
     pub enum Error {
         /// Target member does not exist.
         MemberDoesNotExist,
@@ -52,6 +59,94 @@ pub mod proposal_manager {
         /// Invalid Member Manager Call
         InvalidMemberManagerCall,
         InvalidDaoManagerCall,
+        /// Out of the voting period
+        OutOfVotingPeriod,
+        /// The voting period has not finished yet.
+        VotingPeriodNotFinished,
+        /// Invalid Governance Parameters
+        InvalidGovernanceParameters,
+        /// The governance token is not set.
+        GovernanceTokenIsNotSet,
+        /// The voter has no voting power.
+        NoVotingPower,
+        /// The voter was not a member when the voting opened.
+        NotEligibleVoter,
+        /// Invalid Delegate
+        InvalidDelegate,
+        /// The delegation does not exist.
+        DelegationDoesNotExist,
+        /// Invalid Proposal Options
+        InvalidProposalOptions,
+        /// Invalid Ranking
+        InvalidRanking,
+        /// The voting method does not match the proposal type.
+        InvalidVotingMethod,
+        /// The winning option does not exist.
+        WinningOptionDoesNotExist,
+        /// Only Proposer does.
+        OnlyProposerDoes,
+        /// The proposal can not be cancelled.
+        CanNotCancel,
+        /// The execution deadline has passed.
+        ExecutionDeadlinePassed,
+        /// The timelock period has not finished yet.
+        TimelockNotElapsed,
+        /// The reason of the veto is required.
+        VetoReasonIsRequired,
+        /// Already Vetoed.
+        AlreadyVetoed,
+        /// Invalid Proposal Actions
+        InvalidProposalActions,
+        /// The transferred value does not match the proposal deposit.
+        InvalidDeposit,
+        /// The commitment of the vote does not exist.
+        CommitmentDoesNotExist,
+        /// The revealed vote does not match the commitment.
+        InvalidCommitment,
+        /// Out of the reveal period
+        OutOfRevealPeriod,
+        /// The reveal period has not finished yet.
+        RevealPeriodNotFinished,
+        /// The proposal can not be amended.
+        CanNotAmend,
+        /// The revision does not match the current revision of the proposal.
+        RevisionMismatch,
+        /// The proposal type is not allowed for the emergency proposal.
+        NotEmergencyProposalType,
+        /// The csv data is rejected by the member manager.
+        InvalidMemberManagerCsvData(MemberManagerError),
+        /// The csv data is rejected by the dao manager.
+        InvalidDaoManagerCsvData(DaoManagerError),
+        /// The election does not exist.
+        ElectionDoesNotExist,
+        /// The election is in progress.
+        ElectionIsInProgress,
+        /// Invalid Seats
+        InvalidSeats,
+        /// Out of the nomination period
+        OutOfNominationPeriod,
+        /// The candidate is already nominated.
+        CandidateAlreadyNominated,
+        /// The count of candidates reached the limit.
+        TooManyCandidates,
+        /// Invalid Candidates
+        InvalidCandidates,
+        /// The change or the recall of electoral commissioners can not be vetoed.
+        CommissionerChangeCanNotBeVetoed,
+        /// The proposal type is not allowed for the optimistic proposal.
+        NotOptimisticProposalType,
+        /// The amount exceeds the optimistic treasury limit.
+        OptimisticLimitExceeded,
+        /// Out of the challenge period
+        OutOfChallengePeriod,
+        /// Already Objected.
+        AlreadyObjected,
+        /// The retry interval of the execution has not elapsed.
+        RetryIntervalNotElapsed,
+        /// Only this contract can call the function.
+        OnlyThisContractDoes,
+        /// Calling the composite actions is failure.
+        CompositeCallIsFailure,
         /// Possible Bug
         PossibleBug,
     }
@@ -77,6 +172,14 @@ pub mod proposal_manager {
         Denied,
         /// finished
         Finished,
+        /// cancelled by the proposer
+        Cancelled,
+        /// not executed before the execution deadline
+        Expired,
+        /// vetoed by electoral commissioners during the timelock period
+        Vetoed,
+        /// the execution failed as many times as the max execution attempts
+        ExecutionFailed,
     }
 
     #[derive(
@@ -92,11 +195,224 @@ pub mod proposal_manager {
         ChangeStatusOfTokenSale,
         WithdrawTokenSales,
         DistributeGovernanceToken,
+        ChangeGovernanceParameters,
+        RankedChoice,
+        Composite,
+        ExternalCall,
+        RecallElectoralCommissioner,
+    }
+
+    #[derive(
+        Debug, PartialEq, Eq, scale::Encode, scale::Decode, Clone, SpreadLayout, PackedLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(StorageLayout, scale_info::TypeInfo))]
+    pub enum VoteType {
+        Yes,
+        No,
+        /// counts toward the turnout but not toward the majority
+        Abstain,
+    }
+
+    #[derive(
+        Debug, PartialEq, Eq, scale::Encode, scale::Decode, Clone, SpreadLayout, PackedLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(StorageLayout, scale_info::TypeInfo))]
+    pub enum BallotChoice {
+        /// the vote for the proposal
+        Vote(VoteType),
+        /// indexes of the options of the ranked choice proposal in order of preference
+        Ranking(Vec<u8>),
+        /// the hash of the secret vote which is not revealed yet
+        Commitment(Hash),
+    }
+
+    #[derive(Debug, Clone, scale::Encode, scale::Decode, SpreadLayout, PackedLayout, PartialEq)]
+    #[cfg_attr(feature = "std", derive(StorageLayout, scale_info::TypeInfo))]
+    pub struct Ballot {
+        choice: BallotChoice,
+        weight: Balance,
+        /// the delegators whose votes are cast by this ballot. ( frozen when the voter votes )
+        delegators: Vec<AccountId>,
+        delegated_weight: Balance,
+        timestamp: u64,
+    }
+
+    /// the error of the callee which is returned when the action is executed.
+    #[derive(
+        Debug, PartialEq, Eq, scale::Encode, scale::Decode, Clone, SpreadLayout, PackedLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(StorageLayout, scale_info::TypeInfo))]
+    pub enum ExecutionError {
+        MemberManager(MemberManagerError),
+        DaoManager(DaoManagerError),
+        ProposalManager(Error),
+    }
+
+    #[derive(Debug, Clone, scale::Encode, scale::Decode, SpreadLayout, PackedLayout, PartialEq)]
+    #[cfg_attr(feature = "std", derive(StorageLayout, scale_info::TypeInfo))]
+    pub struct ExecutionAttempt {
+        /// the attempt number which starts from 1.
+        attempt: u16,
+        /// None means that the execution succeeded.
+        error: Option<ExecutionError>,
+        timestamp: u64,
+    }
+
+    #[derive(
+        Debug, PartialEq, Eq, scale::Encode, scale::Decode, Clone, SpreadLayout, PackedLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(StorageLayout, scale_info::TypeInfo))]
+    pub enum ElectionStatus {
+        /// nominating and voting
+        InProgress,
+        /// the electoral commissioners are elected
+        Finished,
+        /// no candidate is elected
+        Failed,
+    }
+
+    #[derive(Debug, Clone, scale::Encode, scale::Decode, SpreadLayout, PackedLayout, PartialEq)]
+    #[cfg_attr(feature = "std", derive(StorageLayout, scale_info::TypeInfo))]
+    pub struct ElectionInfo {
+        election_id: u128,
+        /// the count of electoral commissioners to be elected.
+        seats: u16,
+        status: ElectionStatus,
+        /// members can nominate candidates until this time, and then vote until voting_end_time.
+        nomination_end_time: u64,
+        voting_end_time: u64,
+        snapshot_block: BlockNumber,
+        membership_epoch: u32,
+        electorate_size: u32,
+        /// the voting mode and the governance token when the election starts.
+        voting_mode: VotingMode,
+        governance_token: Option<AccountId>,
+    }
+
+    #[derive(Debug, Clone, scale::Encode, scale::Decode, PartialEq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct CandidateInfo {
+        candidate: AccountId,
+        votes: Balance,
     }
 
+    /// default values of the governance config.
     pub const MAJORITY_PERCENTAGE_DEFINITION: u16 = 50;
     pub const REQUIRED_VOTER_TURNOUT_PERCENTAGE_DEFINITION: u16 = 80;
-    pub const TENURE_OF_LIMIT: u16 = 5;
+    /// voting period in milliseconds ( 7 days )
+    pub const VOTING_PERIOD: u64 = 7 * 24 * 60 * 60 * 1000;
+    /// 2 days in milliseconds.
+    pub const REVEAL_PERIOD: u64 = 2 * 24 * 60 * 60 * 1000;
+    /// 1 day in milliseconds.
+    pub const EMERGENCY_VOTING_PERIOD: u64 = 24 * 60 * 60 * 1000;
+    pub const EMERGENCY_MAJORITY_PERCENTAGE_DEFINITION: u16 = 67;
+    pub const RECALL_MAJORITY_PERCENTAGE_DEFINITION: u16 = 67;
+    /// 3 days in milliseconds.
+    pub const CHALLENGE_PERIOD: u64 = 3 * 24 * 60 * 60 * 1000;
+    pub const OBJECTION_THRESHOLD: u16 = 2;
+    pub const OPTIMISTIC_TREASURY_LIMIT: Balance = 0;
+    /// native token deposit to add a proposal
+    pub const PROPOSAL_DEPOSIT: Balance = 0;
+    /// timelock period in milliseconds ( 1 day )
+    pub const TIMELOCK_PERIOD: u64 = 24 * 60 * 60 * 1000;
+    /// count of electoral commissioners required to veto the proposal
+    pub const VETO_THRESHOLD: u16 = 1;
+    /// execution period in milliseconds ( 30 days )
+    pub const EXECUTION_PERIOD: u64 = 30 * 24 * 60 * 60 * 1000;
+    /// max count of options of the ranked choice proposal
+    pub const MAX_PROPOSAL_OPTIONS: usize = 16;
+    /// max count of actions of the composite proposal
+    pub const MAX_PROPOSAL_ACTIONS: usize = 16;
+    /// max count of items in a page of the paginated queries
+    pub const MAX_PAGE_SIZE: u32 = 50;
+    /// the max count of proposal ids which are scanned by one paginated query.
+    pub const MAX_SCAN_SIZE: u128 = 500;
+    pub const MAX_EXECUTION_ATTEMPTS: u16 = 3;
+    /// 1 hour in milliseconds.
+    pub const EXECUTION_RETRY_INTERVAL: u64 = 60 * 60 * 1000;
+    /// the selector of execute_composite_actions which is called by this contract itself.
+    pub const EXECUTE_COMPOSITE_ACTIONS_SELECTOR: [u8; 4] = [0x43, 0x4f, 0x4d, 0x50];
+    /// 3 days in milliseconds.
+    pub const NOMINATION_PERIOD: u64 = 3 * 24 * 60 * 60 * 1000;
+    pub const ELECTION_SEATS: u16 = 3;
+    pub const MAX_CANDIDATES: usize = 32;
+
+    #[derive(
+        Debug, PartialEq, Eq, scale::Encode, scale::Decode, Clone, SpreadLayout, PackedLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(StorageLayout, scale_info::TypeInfo))]
+    pub enum VotingMode {
+        /// one vote per member
+        OnePersonOneVote,
+        /// the vote is weighted by the balance of the governance token
+        TokenWeighted,
+    }
+
+    #[derive(Debug, Clone, scale::Encode, scale::Decode, SpreadLayout, PackedLayout, PartialEq)]
+    #[cfg_attr(feature = "std", derive(StorageLayout, scale_info::TypeInfo))]
+    pub struct GovernanceConfig {
+        required_voter_turnout_percentage: u16,
+        majority_percentage: u16,
+        /// the term length of electoral commissioners in milliseconds.
+        term_length: u64,
+        voting_period: u64,
+        reveal_period: u64,
+        execution_period: u64,
+        timelock_period: u64,
+        veto_threshold: u16,
+        proposal_deposit: Balance,
+        voting_mode: VotingMode,
+        governance_token: Option<AccountId>,
+        emergency_voting_period: u64,
+        emergency_majority_percentage: u16,
+        /// the proposal types which can be proposed as the emergency proposal.
+        emergency_proposal_types: Vec<ProposalType>,
+        /// the proposal becomes ExecutionFailed when the execution fails this many times.
+        max_execution_attempts: u16,
+        /// the failed proposal can be executed again after this interval.
+        execution_retry_interval: u64,
+        nomination_period: u64,
+        /// the count of electoral commissioners to be elected in the election.
+        election_seats: u16,
+        recall_majority_percentage: u16,
+        /// the proposal types which can be proposed as the optimistic proposal.
+        optimistic_proposal_types: Vec<ProposalType>,
+        challenge_period: u64,
+        /// the optimistic proposal falls back to the full vote when this many members object.
+        objection_threshold: u16,
+        /// the max amount of UseDaoTresury which can be proposed as the optimistic proposal.
+        optimistic_treasury_limit: Balance,
+    }
+
+    impl Default for GovernanceConfig {
+        fn default() -> Self {
+            Self {
+                required_voter_turnout_percentage: REQUIRED_VOTER_TURNOUT_PERCENTAGE_DEFINITION,
+                majority_percentage: MAJORITY_PERCENTAGE_DEFINITION,
+                term_length: TERM_LENGTH,
+                voting_period: VOTING_PERIOD,
+                reveal_period: REVEAL_PERIOD,
+                execution_period: EXECUTION_PERIOD,
+                timelock_period: TIMELOCK_PERIOD,
+                veto_threshold: VETO_THRESHOLD,
+                proposal_deposit: PROPOSAL_DEPOSIT,
+                voting_mode: VotingMode::OnePersonOneVote,
+                governance_token: None,
+                emergency_voting_period: EMERGENCY_VOTING_PERIOD,
+                emergency_majority_percentage: EMERGENCY_MAJORITY_PERCENTAGE_DEFINITION,
+                emergency_proposal_types: vec![ProposalType::DeleteMember],
+                max_execution_attempts: MAX_EXECUTION_ATTEMPTS,
+                execution_retry_interval: EXECUTION_RETRY_INTERVAL,
+                nomination_period: NOMINATION_PERIOD,
+                election_seats: ELECTION_SEATS,
+                recall_majority_percentage: RECALL_MAJORITY_PERCENTAGE_DEFINITION,
+                optimistic_proposal_types: Vec::new(),
+                challenge_period: CHALLENGE_PERIOD,
+                objection_threshold: OBJECTION_THRESHOLD,
+                optimistic_treasury_limit: OPTIMISTIC_TREASURY_LIMIT,
+            }
+        }
+    }
 
     // #[derive(
     //     Default, Debug, Clone, scale::Encode, scale::Decode, SpreadLayout, PackedLayout, PartialEq,
@@ -113,14 +429,115 @@ pub mod proposal_manager {
         github_url: String,
         status: ProposalStatus,
         csv_data: String,
+        /// the current revision number. ( 0 is the original proposal )
+        revision: u32,
+        /// the emergency proposal has the short voting period and requires the supermajority.
+        is_emergency: bool,
+        /// the optimistic proposal passes after the challenge period unless members object.
+        /// * voting_end_time is the end of the challenge period while the proposal is optimistic.
+        is_optimistic: bool,
+        voting_start_time: u64,
+        voting_end_time: u64,
+        /// the votes are committed in secret and revealed after the voting period.
+        is_secret_ballot: bool,
+        /// the committed votes can be revealed until this time.
+        reveal_end_time: u64,
+        snapshot_block: BlockNumber,
+        /// the membership epoch when the proposal enters Voting. Only members who joined by this epoch can vote.
+        membership_epoch: u32,
+        /// the count of members when the proposal enters Voting.
+        electorate_size: u32,
+        /// the voting mode and the governance token when the proposal enters Voting.
+        /// * The change of the governance config does not affect the proposal in voting.
+        voting_mode: VotingMode,
+        governance_token: Option<AccountId>,
+        /// the proposal can be executed from this time after it passes.
+        executable_time: u64,
+        /// the proposal can be executed until this time after it passes.
+        execution_deadline: u64,
+    }
+
+    /// a lightweight summary of the proposal without the large text fields.
+    #[derive(Debug, Clone, scale::Encode, scale::Decode, PartialEq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct ProposalSummary {
+        proposal_type: ProposalType,
+        proposal_id: u128,
+        proposer: AccountId,
+        title: String,
+        status: ProposalStatus,
+        voting_start_time: u64,
+        voting_end_time: u64,
+        executable_time: u64,
+        execution_deadline: u64,
+    }
+
+    /// filter of the paginated proposal queries.
+    /// * None means that the field is not filtered.
+    /// * The proposals whose id is from from_id to to_id ( exclusive ) are searched.
+    #[derive(Debug, Clone, scale::Encode, scale::Decode, PartialEq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct ProposalFilter {
+        status: Option<ProposalStatus>,
+        proposal_type: Option<ProposalType>,
+        proposer: Option<AccountId>,
+        from_id: u128,
+        to_id: Option<u128>,
+    }
+
+    /// an option of the ranked choice proposal.
+    #[derive(Debug, Clone, scale::Encode, scale::Decode, SpreadLayout, PackedLayout, PartialEq)]
+    #[cfg_attr(feature = "std", derive(StorageLayout, scale_info::TypeInfo))]
+    pub struct ProposalOption {
+        label: String,
+        proposal_type: ProposalType,
+        csv_data: String,
+    }
+
+    /// an action of the composite proposal.
+    #[derive(Debug, Clone, scale::Encode, scale::Decode, SpreadLayout, PackedLayout, PartialEq)]
+    #[cfg_attr(feature = "std", derive(StorageLayout, scale_info::TypeInfo))]
+    pub struct ProposalAction {
+        proposal_type: ProposalType,
+        csv_data: String,
     }
 
     #[derive(Debug, Clone, scale::Encode, scale::Decode, SpreadLayout, PackedLayout, PartialEq)]
     #[cfg_attr(feature = "std", derive(StorageLayout, scale_info::TypeInfo))]
     pub struct VotingResult {
         proposal_id: u128,
-        yes: u16,
-        no: u16,
+        yes: Balance,
+        no: Balance,
+        abstain: Balance,
+        /// the weight of the votes which are cast by delegates. ( included in yes, no and abstain )
+        delegated: Balance,
+    }
+
+    #[derive(Debug, Clone, scale::Encode, scale::Decode, SpreadLayout, PackedLayout, PartialEq)]
+    #[cfg_attr(feature = "std", derive(StorageLayout, scale_info::TypeInfo))]
+    pub struct ProposalRevision {
+        revision: u32,
+        title: String,
+        outline: String,
+        details: String,
+        github_url: String,
+        csv_data: String,
+        timestamp: u64,
+    }
+
+    #[derive(Debug, Clone, scale::Encode, scale::Decode, SpreadLayout, PackedLayout, PartialEq)]
+    #[cfg_attr(feature = "std", derive(StorageLayout, scale_info::TypeInfo))]
+    pub struct VetoInfo {
+        electoral_commissioner: AccountId,
+        reason: String,
+        timestamp: u64,
+    }
+
+    #[derive(Debug, Clone, scale::Encode, scale::Decode, SpreadLayout, PackedLayout, PartialEq)]
+    #[cfg_attr(feature = "std", derive(StorageLayout, scale_info::TypeInfo))]
+    pub struct DelegationInfo {
+        delegator: AccountId,
+        delegate: AccountId,
     }
 
     #[ink(storage)]
@@ -131,16 +548,56 @@ pub mod proposal_manager {
         member_manager: MemberManagerRef,
         /// dao_manager reference
         dao_manager: DaoManagerRef,
-        /// dao_address => count of tenure
-        count_of_tenure: Mapping<AccountId, u16>,
         /// ( dao address, proposal_id) => proposal info
         proposal_infoes: Mapping<(AccountId, u128), ProposalInfo>,
         /// ( dao address, proposal_id) => voting result
         voting_results: Mapping<(AccountId, u128), VotingResult>,
-        /// ( dao address, proposal_id) => eoa address
-        voted_people: Mapping<(AccountId, u128), Vec<AccountId>>,
+        /// ( dao address, proposal_id, eoa address ) => ballot
+        ballots: Mapping<(AccountId, u128, AccountId), Ballot>,
+        /// ( dao address, proposal_id, ballot_no ) => eoa address
+        ballot_voters: Mapping<(AccountId, u128, u32), AccountId>,
+        /// ( dao address, proposal_id ) => count of ballots
+        ballot_counts: Mapping<(AccountId, u128), u32>,
         /// dao address => u128
         next_proposal_ids:Mapping<AccountId, u128>,
+        /// dao address => governance config
+        governance_configs: Mapping<AccountId, GovernanceConfig>,
+        /// ( dao address, delegator ) => delegate
+        delegations: Mapping<(AccountId, AccountId), AccountId>,
+        /// ( dao address, delegate ) => delegators
+        delegators: Mapping<(AccountId, AccountId), Vec<AccountId>>,
+        /// ( dao address, proposal_id, delegator ) => delegate whose ballot casts the delegator's vote
+        delegated_ballots: Mapping<(AccountId, u128, AccountId), AccountId>,
+        /// ( dao address, proposal_id ) => options of the ranked choice proposal
+        proposal_options: Mapping<(AccountId, u128), Vec<ProposalOption>>,
+        /// ( dao address, proposal_id ) => index of the winning option
+        winning_options: Mapping<(AccountId, u128), u8>,
+        /// ( dao address, proposal_id ) => actions of the composite proposal
+        proposal_actions: Mapping<(AccountId, u128), Vec<ProposalAction>>,
+        /// ( dao address, proposal_id ) => vetoes of electoral commissioners
+        vetoes: Mapping<(AccountId, u128), Vec<VetoInfo>>,
+        /// ( dao address, proposal_id ) => deposit of the proposer
+        deposits: Mapping<(AccountId, u128), Balance>,
+        /// ( dao address, proposal_id, revision ) => revision of the proposal
+        proposal_revisions: Mapping<(AccountId, u128, u32), ProposalRevision>,
+        /// ( dao address, proposal_id, attempt ) => execution attempt
+        execution_attempts: Mapping<(AccountId, u128, u16), ExecutionAttempt>,
+        /// ( dao address, proposal_id ) => count of execution attempts
+        execution_attempt_counts: Mapping<(AccountId, u128), u16>,
+        /// ( dao address, election_id ) => election info
+        elections: Mapping<(AccountId, u128), ElectionInfo>,
+        /// dao address => next election_id
+        next_election_ids: Mapping<AccountId, u128>,
+        /// ( dao address, election_id ) => candidates in order of nomination
+        candidates: Mapping<(AccountId, u128), Vec<AccountId>>,
+        /// ( dao address, election_id, candidate ) => votes
+        candidate_votes: Mapping<(AccountId, u128, AccountId), Balance>,
+        /// ( dao address, election_id, voter ) => chosen candidates
+        election_ballots: Mapping<(AccountId, u128, AccountId), Vec<AccountId>>,
+        /// ( dao address, election_id ) => total weight of the voters
+        election_turnouts: Mapping<(AccountId, u128), Balance>,
+        /// ( dao address, proposal_id ) => members who object to the optimistic proposal
+        objections: Mapping<(AccountId, u128), Vec<AccountId>>,
     }
 
     impl ProposalManager {
@@ -153,16 +610,39 @@ pub mod proposal_manager {
             Self {
                 member_manager: _member_manager,
                 dao_manager : dao_manager,
-                count_of_tenure: Mapping::default(),
                 proposal_infoes: Mapping::default(),
                 voting_results: Mapping::default(),
-                voted_people: Mapping::default(),
+                ballots: Mapping::default(),
+                ballot_voters: Mapping::default(),
+                ballot_counts: Mapping::default(),
                 next_proposal_ids: Mapping::default(), 
+                governance_configs: Mapping::default(),
+                delegations: Mapping::default(),
+                delegators: Mapping::default(),
+                delegated_ballots: Mapping::default(),
+                proposal_options: Mapping::default(),
+                winning_options: Mapping::default(),
+                proposal_actions: Mapping::default(),
+                vetoes: Mapping::default(),
+                deposits: Mapping::default(),
+                proposal_revisions: Mapping::default(),
+                execution_attempts: Mapping::default(),
+                execution_attempt_counts: Mapping::default(),
+                elections: Mapping::default(),
+                next_election_ids: Mapping::default(),
+                candidates: Mapping::default(),
+                candidate_votes: Mapping::default(),
+                election_ballots: Mapping::default(),
+                election_turnouts: Mapping::default(),
+                objections: Mapping::default(),
             }
         }
 
         /// add_proposal
+        /// * The caller has to pay the proposal deposit of the DAO.
+        /// * csv_data is decoded in the same way as the execution and the invalid data is rejected.
         #[ink(message)]
+        #[ink(payable)]
         pub fn add_proposal(
             &mut self,
             proposal_type: ProposalType,
@@ -173,78 +653,88 @@ pub mod proposal_manager {
             github_url: String,
             csv_data: String,
         ) -> Result<()> {
-            let caller = self.env().caller();
-            if self
-                .member_manager
-                .modifier_only_member(caller, dao_address)
-                == false
-            {
-                ink_env::debug_println!("################### not member error.");
-                return Err(Error::OnlyMemberDoes);
-            }
-
-            let limit = self.is_limit_tenure_count_of_electoral_commissioner(dao_address);
-            match proposal_type {
-                ProposalType::ChangeElectoralCommissioner => match limit {
-                    true => (),
-                    false => return Err(Error::NotExpirationOfTermOfElectionCommissioner),
-                },
-                _ => match limit {
-                    true => return Err(Error::ExpirationOfTermOfElectionCommissioner),
-                    false => (),
-                },
-            };
-
-            let mut next_proposal_id = match self.next_proposal_ids.get(&dao_address) {
-                Some(value) => value,
-                None => 0,
+            let result = match proposal_type {
+                ProposalType::RankedChoice => Err(Error::InvalidProposalOptions),
+                ProposalType::Composite => Err(Error::InvalidProposalActions),
+                _ => self.inline_add_proposal(proposal_type, dao_address, title, outline, details, github_url, csv_data, false),
             };
+            match self.inline_return_transferred_value_on_error(result) {
+                Ok(_proposal_id) => Ok(()),
+                Err(e) => Err(e),
+            }
+        }
 
-            let proposal_info = ProposalInfo {
-                proposal_type: proposal_type,
-                proposal_id: next_proposal_id,
-                title: title,
-                outline: outline,
-                details: details,
-                status: self::ProposalStatus::Proposed,
-                proposer: caller,
-                github_url: github_url,
-                csv_data: csv_data,
+        /// add the emergency proposal
+        /// * The voting period is the emergency voting period of the DAO and the emergency majority is required to pass.
+        /// * Only the emergency proposal types of the DAO can be proposed.
+        /// * The caller has to pay the proposal deposit of the DAO.
+        #[ink(message)]
+        #[ink(payable)]
+        pub fn add_emergency_proposal(
+            &mut self,
+            proposal_type: ProposalType,
+            dao_address: AccountId,
+            title: String,
+            outline: String,
+            details: String,
+            github_url: String,
+            csv_data: String,
+        ) -> Result<()> {
+            let config = self.get_governance_config(dao_address);
+            let result = match config.emergency_proposal_types.contains(&proposal_type) {
+                true => self.inline_add_proposal(proposal_type, dao_address, title, outline, details, github_url, csv_data, true),
+                false => Err(Error::NotEmergencyProposalType),
             };
-            self.proposal_infoes
-                .insert(&(dao_address, next_proposal_id), &proposal_info);
-            next_proposal_id = next_proposal_id + 1;
-            self.next_proposal_ids.insert(&dao_address, &next_proposal_id);
-            Ok(())
+            match self.inline_return_transferred_value_on_error(result) {
+                Ok(_proposal_id) => Ok(()),
+                Err(e) => Err(e),
+            }
         }
 
-        /// get proposal list.
+        /// add the optimistic proposal
+        /// * The proposal passes after the challenge period unless the objection threshold of members object.
+        /// * When enough members object, the proposal falls back to the full vote.
+        /// * Only the optimistic proposal types of the DAO can be proposed.
+        /// * The amount of UseDaoTresury has to be within the limit.
+        /// * The caller has to pay the proposal deposit of the DAO.
         #[ink(message)]
-        pub fn get_proposal_list(&self, dao_address: AccountId) -> Vec<ProposalInfo> {
-            let next_proposal_id = match self.next_proposal_ids.get(&dao_address) {
-                Some(value) => value,
-                None => 0,
+        #[ink(payable)]
+        pub fn add_optimistic_proposal(
+            &mut self,
+            proposal_type: ProposalType,
+            dao_address: AccountId,
+            title: String,
+            outline: String,
+            details: String,
+            github_url: String,
+            csv_data: String,
+        ) -> Result<()> {
+            let config = self.get_governance_config(dao_address);
+            let result = match self.check_optimistic_proposal(dao_address, &proposal_type, &csv_data) {
+                Ok(()) => self.inline_add_proposal(proposal_type, dao_address, title, outline, details, github_url, csv_data, false),
+                Err(e) => Err(e),
+            };
+            let proposal_id = match self.inline_return_transferred_value_on_error(result) {
+                Ok(value) => value,
+                Err(e) => return Err(e),
             };
 
-            let mut proposal_list: Vec<ProposalInfo> = Vec::new();
-            for i in 0..next_proposal_id {
-                let proposal_info = match self.proposal_infoes.get(&(dao_address, i)) {
+            let mut proposal_info: ProposalInfo =
+                match self.proposal_infoes.get(&(dao_address, proposal_id)) {
                     Some(value) => value,
-                    None => continue,
+                    None => return Err(Error::PossibleBug),
                 };
-                proposal_list.push(proposal_info.clone());
-            }
-            proposal_list
+            proposal_info.is_optimistic = true;
+            proposal_info.voting_end_time = proposal_info.voting_start_time + config.challenge_period;
+            proposal_info.reveal_end_time = proposal_info.voting_end_time;
+            self.inline_change_proposal_status(dao_address, proposal_info);
+            Ok(())
         }
 
-        /// vote for the proposal.
+        /// object to the optimistic proposal during the challenge period.
+        /// * The proposal falls back to the full vote when the objections reach the objection threshold.
         #[ink(message)]
-        pub fn vote_for_the_proposal(
-            &mut self,
-            dao_address: AccountId,
-            proposal_id: u128,
-            vote_yes: bool,
-        ) -> Result<()> {
+        pub fn object_to_proposal(&mut self, dao_address: AccountId, proposal_id: u128) -> Result<()> {
             let caller = self.env().caller();
             if self
                 .member_manager
@@ -253,292 +743,2477 @@ pub mod proposal_manager {
             {
                 return Err(Error::OnlyMemberDoes);
             }
-
-            let proposal_info: ProposalInfo =
+            let mut proposal_info: ProposalInfo =
                 match self.proposal_infoes.get(&(dao_address, proposal_id)) {
                     Some(value) => value,
                     None => return Err(Error::ProposalDoesNotExist),
                 };
-            if proposal_info.status != ProposalStatus::Voting {
-                return Err(Error::IncorrectVotingStatus);
+            if !proposal_info.is_optimistic
+                || proposal_info.status != ProposalStatus::Proposed
+                || self.env().block_timestamp() >= proposal_info.voting_end_time
+            {
+                return Err(Error::OutOfChallengePeriod);
             }
 
-            let mut voted_list: Vec<AccountId> =
-                match self.voted_people.get(&(dao_address, proposal_id)) {
-                    Some(value) => match value.contains(&caller) {
-                        true => return Err(Error::AlreadyVoted),
-                        _ => value,
-                    },
-                    None => Vec::<AccountId>::new(),
-                };
-            voted_list.push(caller);
-            self.voted_people
-                .insert(&(dao_address, proposal_id), &voted_list);
-
-            let mut yes_value = 0;
-            let mut no_value = 0;
-            match vote_yes {
-                true => yes_value = yes_value + 1,
-                false => no_value = no_value + 1,
-            };
+            let mut objection_list = self.get_objections(dao_address, proposal_id);
+            if objection_list.contains(&caller) {
+                return Err(Error::AlreadyObjected);
+            }
+            objection_list.push(caller);
+            self.objections.insert(&(dao_address, proposal_id), &objection_list);
 
-            let vote_result: VotingResult =
-                match self.voting_results.get(&(dao_address, proposal_id)) {
-                    Some(mut value) => {
-                        value.yes = value.yes + yes_value;
-                        value.no = value.no + no_value;
-                        value
-                    }
-                    None => VotingResult {
-                        proposal_id: proposal_id,
-                        yes: yes_value,
-                        no: no_value,
-                    },
-                };
-            self.voting_results
-                .insert(&(dao_address, proposal_id), &vote_result);
+            let config = self.get_governance_config(dao_address);
+            if objection_list.len() >= config.objection_threshold as usize {
+                // fall back to the full vote from now.
+                proposal_info.is_optimistic = false;
+                proposal_info = self.inline_set_voting_schedule(dao_address, proposal_info);
+                self.inline_change_proposal_status(dao_address, proposal_info);
+            }
             Ok(())
         }
 
-        /// get voting result
+        /// get members who object to the optimistic proposal.
         #[ink(message)]
-        pub fn get_voted_result(
-            &self,
-            _dao_address: AccountId,
-            _proposal_id: u128,
-        ) -> Option<VotingResult> {
-            self.voting_results.get(&(_dao_address, _proposal_id))
+        pub fn get_objections(&self, dao_address: AccountId, proposal_id: u128) -> Vec<AccountId> {
+            match self.objections.get(&(dao_address, proposal_id)) {
+                Some(value) => value,
+                None => Vec::new(),
+            }
         }
 
-        /// change the proposal status
+        /// add the ranked choice proposal
+        /// * Only the winning option of the instant-runoff tally is executed.
+        /// * The caller has to pay the proposal deposit of the DAO.
         #[ink(message)]
-        pub fn change_proposal_status(
+        #[ink(payable)]
+        pub fn add_ranked_choice_proposal(
             &mut self,
-            _dao_address: AccountId,
-            _proposal_id: u128,
-            _status: ProposalStatus,
+            dao_address: AccountId,
+            title: String,
+            outline: String,
+            details: String,
+            github_url: String,
+            options: Vec<ProposalOption>,
         ) -> Result<()> {
-            let caller = self.env().caller();
-            if self
-                .member_manager
-                .modifier_only_electoral_commissioner(caller, _dao_address)
-                == false
-            {
-                return Err(Error::OnlyElectoralCommissioner);
-            }
-
-            let mut proposal_info: ProposalInfo =
-                match self.proposal_infoes.get(&(_dao_address, _proposal_id)) {
-                    Some(value) => value,
-                    None => return Err(Error::ProposalDoesNotExist),
-                };
-
-            let limit = self.is_limit_tenure_count_of_electoral_commissioner(_dao_address);
-            match proposal_info.proposal_type {
-                ProposalType::ChangeElectoralCommissioner => match limit {
-                    true => (),
-                    false => return Err(Error::NotExpirationOfTermOfElectionCommissioner),
-                },
-                _ => match limit {
-                    true => return Err(Error::ExpirationOfTermOfElectionCommissioner),
-                    false => (),
-                },
+            let result = match self.check_proposal_options(dao_address, &options) {
+                Ok(()) => self.inline_add_proposal(
+                    ProposalType::RankedChoice,
+                    dao_address,
+                    title,
+                    outline,
+                    details,
+                    github_url,
+                    String::new(),
+                    false,
+                ),
+                Err(e) => Err(e),
+            };
+            let proposal_id = match self.inline_return_transferred_value_on_error(result) {
+                Ok(value) => value,
+                Err(e) => return Err(e),
             };
+            self.proposal_options.insert(&(dao_address, proposal_id), &options);
+            Ok(())
+        }
 
-            match self.check_anti_pattern(proposal_info.clone(), _status.clone()) {
-                true => {
-                    proposal_info.status = _status.clone();
-                    self.inline_change_proposal_status(_dao_address, proposal_info.clone())
-                }
-                false => return Err(Error::InvalidChanging),
-            }
-            if _status == ProposalStatus::FinishVoting {
-                match self.count_votes_of_proposal(_dao_address, _proposal_id) {
+        /// add the composite proposal
+        /// * The actions are executed in order, and all of them are reverted if one of them fails.
+        /// * The caller has to pay the proposal deposit of the DAO.
+        #[ink(message)]
+        #[ink(payable)]
+        pub fn add_composite_proposal(
+            &mut self,
+            dao_address: AccountId,
+            title: String,
+            outline: String,
+            details: String,
+            github_url: String,
+            actions: Vec<ProposalAction>,
+        ) -> Result<()> {
+            let result = match self.check_proposal_actions(dao_address, &actions) {
+                Ok(()) => self.inline_add_proposal(
+                    ProposalType::Composite,
+                    dao_address,
+                    title,
+                    outline,
+                    details,
+                    github_url,
+                    String::new(),
+                    false,
+                ),
+                Err(e) => Err(e),
+            };
+            let proposal_id = match self.inline_return_transferred_value_on_error(result) {
+                Ok(value) => value,
+                Err(e) => return Err(e),
+            };
+            self.proposal_actions.insert(&(dao_address, proposal_id), &actions);
+            Ok(())
+        }
+
+        /// amend the proposal.
+        /// * Only the proposer can amend the proposal while it is Proposed and the voting period has not finished.
+        /// * The voting period restarts from the amendment.
+        /// * The revision number is incremented and the previous revisions are kept.
+        #[ink(message)]
+        pub fn amend_proposal(
+            &mut self,
+            dao_address: AccountId,
+            proposal_id: u128,
+            title: String,
+            outline: String,
+            details: String,
+            github_url: String,
+            csv_data: String,
+        ) -> Result<()> {
+            let caller = self.env().caller();
+            let mut proposal_info: ProposalInfo =
+                match self.proposal_infoes.get(&(dao_address, proposal_id)) {
+                    Some(value) => value,
+                    None => return Err(Error::ProposalDoesNotExist),
+                };
+            if proposal_info.proposer != caller {
+                return Err(Error::OnlyProposerDoes);
+            }
+            if proposal_info.status != ProposalStatus::Proposed
+                || self.env().block_timestamp() >= proposal_info.voting_end_time
+            {
+                return Err(Error::CanNotAmend);
+            }
+            match self.check_csv_data(dao_address, &proposal_info.proposal_type, &csv_data) {
+                Ok(()) => (),
+                Err(e) => return Err(e),
+            }
+            if proposal_info.is_optimistic {
+                match self.check_optimistic_proposal(dao_address, &proposal_info.proposal_type, &csv_data) {
+                    Ok(()) => (),
+                    Err(e) => return Err(e),
+                }
+            }
+            // members have the full voting period ( or challenge period ) for the amended proposal.
+            proposal_info = self.inline_set_voting_schedule(dao_address, proposal_info);
+
+            proposal_info.title = title;
+            proposal_info.outline = outline;
+            proposal_info.details = details;
+            proposal_info.github_url = github_url;
+            proposal_info.csv_data = csv_data;
+            proposal_info.revision = proposal_info.revision + 1;
+            self.inline_change_proposal_status(dao_address, proposal_info.clone());
+            self.inline_add_revision(dao_address, proposal_info);
+            Ok(())
+        }
+
+        /// get the revision of the proposal.
+        #[ink(message)]
+        pub fn get_proposal_revision(
+            &self,
+            dao_address: AccountId,
+            proposal_id: u128,
+            revision: u32,
+        ) -> Option<ProposalRevision> {
+            self.proposal_revisions.get(&(dao_address, proposal_id, revision))
+        }
+
+        /// get all revisions of the proposal in order.
+        #[ink(message)]
+        pub fn get_proposal_revisions(&self, dao_address: AccountId, proposal_id: u128) -> Vec<ProposalRevision> {
+            let mut revision_list: Vec<ProposalRevision> = Vec::new();
+            let proposal_info: ProposalInfo =
+                match self.proposal_infoes.get(&(dao_address, proposal_id)) {
+                    Some(value) => value,
+                    None => return revision_list,
+                };
+            for i in 0..=proposal_info.revision {
+                match self.proposal_revisions.get(&(dao_address, proposal_id, i)) {
+                    Some(value) => revision_list.push(value),
+                    None => continue,
+                }
+            }
+            revision_list
+        }
+
+        /// get the deposit of the proposal which is not returned or forfeited yet.
+        #[ink(message)]
+        pub fn get_proposal_deposit(&self, dao_address: AccountId, proposal_id: u128) -> Option<Balance> {
+            self.deposits.get(&(dao_address, proposal_id))
+        }
+
+        /// get actions of the composite proposal.
+        #[ink(message)]
+        pub fn get_proposal_actions(&self, dao_address: AccountId, proposal_id: u128) -> Vec<ProposalAction> {
+            match self.proposal_actions.get(&(dao_address, proposal_id)) {
+                Some(value) => value,
+                None => Vec::new(),
+            }
+        }
+
+        /// get options of the ranked choice proposal.
+        #[ink(message)]
+        pub fn get_proposal_options(&self, dao_address: AccountId, proposal_id: u128) -> Vec<ProposalOption> {
+            match self.proposal_options.get(&(dao_address, proposal_id)) {
+                Some(value) => value,
+                None => Vec::new(),
+            }
+        }
+
+        /// get the index of the winning option of the ranked choice proposal.
+        #[ink(message)]
+        pub fn get_winning_option(&self, dao_address: AccountId, proposal_id: u128) -> Option<u8> {
+            self.winning_options.get(&(dao_address, proposal_id))
+        }
+
+        /// get proposal list.
+        #[ink(message)]
+        pub fn get_proposal_list(&self, dao_address: AccountId) -> Vec<ProposalInfo> {
+            let next_proposal_id = match self.next_proposal_ids.get(&dao_address) {
+                Some(value) => value,
+                None => 0,
+            };
+
+            let mut proposal_list: Vec<ProposalInfo> = Vec::new();
+            for i in 0..next_proposal_id {
+                let proposal_info = match self.proposal_infoes.get(&(dao_address, i)) {
+                    Some(value) => value,
+                    None => continue,
+                };
+                proposal_list.push(proposal_info.clone());
+            }
+            proposal_list
+        }
+
+        /// get the filtered proposal list.
+        /// * At most limit ( from 1 up to MAX_PAGE_SIZE ) proposals are returned in order of id.
+        /// * At most MAX_SCAN_SIZE proposal ids are scanned, so the page may not be full.
+        /// * The next cursor is returned with the list. To get the next page, set from_id of the filter to it.
+        ///   None means that all proposals until to_id have been scanned.
+        #[ink(message)]
+        pub fn get_proposal_list_paginated(
+            &self,
+            dao_address: AccountId,
+            filter: ProposalFilter,
+            limit: u32,
+        ) -> (Vec<ProposalInfo>, Option<u128>) {
+            self.inline_get_filtered_proposals(dao_address, filter, limit)
+        }
+
+        /// get the filtered proposal summaries.
+        /// * At most limit ( from 1 up to MAX_PAGE_SIZE ) summaries are returned in order of id.
+        /// * At most MAX_SCAN_SIZE proposal ids are scanned, so the page may not be full.
+        /// * The next cursor is returned with the list. To get the next page, set from_id of the filter to it.
+        ///   None means that all proposals until to_id have been scanned.
+        #[ink(message)]
+        pub fn get_proposal_summaries(
+            &self,
+            dao_address: AccountId,
+            filter: ProposalFilter,
+            limit: u32,
+        ) -> (Vec<ProposalSummary>, Option<u128>) {
+            let mut summary_list: Vec<ProposalSummary> = Vec::new();
+            let (proposal_list, next_cursor) = self.inline_get_filtered_proposals(dao_address, filter, limit);
+            for proposal_info in proposal_list {
+                summary_list.push(ProposalSummary {
+                    proposal_type: proposal_info.proposal_type,
+                    proposal_id: proposal_info.proposal_id,
+                    proposer: proposal_info.proposer,
+                    title: proposal_info.title,
+                    status: proposal_info.status,
+                    voting_start_time: proposal_info.voting_start_time,
+                    voting_end_time: proposal_info.voting_end_time,
+                    executable_time: proposal_info.executable_time,
+                    execution_deadline: proposal_info.execution_deadline,
+                });
+            }
+            (summary_list, next_cursor)
+        }
+
+        /// vote for the proposal.
+        /// * The vote is accepted only between voting_start_time and voting_end_time.
+        /// * The first vote moves the proposal from Proposed to Voting and records the snapshot block.
+        /// * Members who joined after the proposal entered Voting can not vote.
+        /// * A member who already voted can change the vote while the proposal is Voting.
+        /// * revision: the revision of the proposal which the voter votes for. It must be the current revision.
+        #[ink(message)]
+        pub fn vote_for_the_proposal(
+            &mut self,
+            dao_address: AccountId,
+            proposal_id: u128,
+            revision: u32,
+            vote: VoteType,
+        ) -> Result<()> {
+            let caller = self.env().caller();
+            if self
+                .member_manager
+                .modifier_only_member(caller, dao_address)
+                == false
+            {
+                return Err(Error::OnlyMemberDoes);
+            }
+
+            let mut proposal_info: ProposalInfo =
+                match self.proposal_infoes.get(&(dao_address, proposal_id)) {
+                    Some(value) => value,
+                    None => return Err(Error::ProposalDoesNotExist),
+                };
+            if proposal_info.proposal_type == ProposalType::RankedChoice || proposal_info.is_secret_ballot {
+                return Err(Error::InvalidVotingMethod);
+            }
+            match proposal_info.status {
+                ProposalStatus::Proposed | ProposalStatus::Voting => (),
+                _ => return Err(Error::IncorrectVotingStatus),
+            }
+            if !self.is_in_voting_period(proposal_info.clone()) {
+                return Err(Error::OutOfVotingPeriod);
+            }
+            if proposal_info.revision != revision {
+                return Err(Error::RevisionMismatch);
+            }
+
+            if proposal_info.status == ProposalStatus::Proposed {
+                proposal_info = self.inline_open_voting(dao_address, proposal_info);
+            }
+            if self
+                .member_manager
+                .modifier_only_member_at_epoch(caller, dao_address, proposal_info.membership_epoch)
+                == false
+            {
+                return Err(Error::NotEligibleVoter);
+            }
+
+            let weight = match self.get_vote_weight(
+                caller,
+                proposal_info.snapshot_block,
+                &proposal_info.voting_mode,
+                proposal_info.governance_token,
+            ) {
+                Ok(value) => value,
+                Err(e) => return Err(e),
+            };
+            let mut vote_result: VotingResult =
+                match self.voting_results.get(&(dao_address, proposal_id)) {
+                    Some(value) => value,
+                    None => VotingResult {
+                        proposal_id: proposal_id,
+                        yes: 0,
+                        no: 0,
+                        abstain: 0,
+                        delegated: 0,
+                    },
+                };
+            match self.ballots.get(&(dao_address, proposal_id, caller)) {
+                Some(Ballot {
+                    choice: BallotChoice::Vote(value),
+                    weight: previous_weight,
+                    ..
+                }) => vote_result = self.inline_remove_vote(vote_result, value, previous_weight),
+                _ => (),
+            }
+            self.inline_record_ballot(dao_address, proposal_info.clone(), caller, BallotChoice::Vote(vote.clone()), weight);
+            vote_result = self.inline_add_vote(vote_result, vote, weight);
+
+            self.voting_results
+                .insert(&(dao_address, proposal_id), &vote_result);
+            Ok(())
+        }
+
+        /// vote for the ranked choice proposal.
+        /// * ranking: indexes of the options in order of preference. It does not need to rank every option.
+        /// * A member who already voted can change the ranking while the proposal is Voting.
+        /// * revision: the revision of the proposal which the voter votes for. It must be the current revision.
+        #[ink(message)]
+        pub fn vote_ranked_choice(
+            &mut self,
+            dao_address: AccountId,
+            proposal_id: u128,
+            revision: u32,
+            ranking: Vec<u8>,
+        ) -> Result<()> {
+            let caller = self.env().caller();
+            if self
+                .member_manager
+                .modifier_only_member(caller, dao_address)
+                == false
+            {
+                return Err(Error::OnlyMemberDoes);
+            }
+
+            let mut proposal_info: ProposalInfo =
+                match self.proposal_infoes.get(&(dao_address, proposal_id)) {
+                    Some(value) => value,
+                    None => return Err(Error::ProposalDoesNotExist),
+                };
+            if proposal_info.proposal_type != ProposalType::RankedChoice {
+                return Err(Error::InvalidVotingMethod);
+            }
+            match proposal_info.status {
+                ProposalStatus::Proposed | ProposalStatus::Voting => (),
+                _ => return Err(Error::IncorrectVotingStatus),
+            }
+            if !self.is_in_voting_period(proposal_info.clone()) {
+                return Err(Error::OutOfVotingPeriod);
+            }
+            if proposal_info.revision != revision {
+                return Err(Error::RevisionMismatch);
+            }
+
+            let option_count = self.get_proposal_options(dao_address, proposal_id).len();
+            if ranking.len() == 0 {
+                return Err(Error::InvalidRanking);
+            }
+            for i in 0..ranking.len() {
+                if ranking[i] as usize >= option_count || ranking[..i].contains(&ranking[i]) {
+                    return Err(Error::InvalidRanking);
+                }
+            }
+
+            if proposal_info.status == ProposalStatus::Proposed {
+                proposal_info = self.inline_open_voting(dao_address, proposal_info);
+            }
+            if self
+                .member_manager
+                .modifier_only_member_at_epoch(caller, dao_address, proposal_info.membership_epoch)
+                == false
+            {
+                return Err(Error::NotEligibleVoter);
+            }
+
+            let weight = match self.get_vote_weight(
+                caller,
+                proposal_info.snapshot_block,
+                &proposal_info.voting_mode,
+                proposal_info.governance_token,
+            ) {
+                Ok(value) => value,
+                Err(e) => return Err(e),
+            };
+            self.inline_record_ballot(dao_address, proposal_info.clone(), caller, BallotChoice::Ranking(ranking), weight);
+            Ok(())
+        }
+
+        /// commit the secret vote for the secret ballot proposal.
+        /// * commitment: the hash which is got by get_vote_commitment.
+        /// * A member who already committed can change the commitment while the proposal is Voting.
+        /// * revision: the revision of the proposal which the voter votes for. It must be the current revision.
+        #[ink(message)]
+        pub fn commit_vote(
+            &mut self,
+            dao_address: AccountId,
+            proposal_id: u128,
+            revision: u32,
+            commitment: Hash,
+        ) -> Result<()> {
+            let caller = self.env().caller();
+            if self
+                .member_manager
+                .modifier_only_member(caller, dao_address)
+                == false
+            {
+                return Err(Error::OnlyMemberDoes);
+            }
+
+            let mut proposal_info: ProposalInfo =
+                match self.proposal_infoes.get(&(dao_address, proposal_id)) {
+                    Some(value) => value,
+                    None => return Err(Error::ProposalDoesNotExist),
+                };
+            if !proposal_info.is_secret_ballot {
+                return Err(Error::InvalidVotingMethod);
+            }
+            match proposal_info.status {
+                ProposalStatus::Proposed | ProposalStatus::Voting => (),
+                _ => return Err(Error::IncorrectVotingStatus),
+            }
+            if !self.is_in_voting_period(proposal_info.clone()) {
+                return Err(Error::OutOfVotingPeriod);
+            }
+            if proposal_info.revision != revision {
+                return Err(Error::RevisionMismatch);
+            }
+
+            if proposal_info.status == ProposalStatus::Proposed {
+                proposal_info = self.inline_open_voting(dao_address, proposal_info);
+            }
+            if self
+                .member_manager
+                .modifier_only_member_at_epoch(caller, dao_address, proposal_info.membership_epoch)
+                == false
+            {
+                return Err(Error::NotEligibleVoter);
+            }
+
+            let weight = match self.get_vote_weight(
+                caller,
+                proposal_info.snapshot_block,
+                &proposal_info.voting_mode,
+                proposal_info.governance_token,
+            ) {
+                Ok(value) => value,
+                Err(e) => return Err(e),
+            };
+            self.inline_record_ballot(dao_address, proposal_info.clone(), caller, BallotChoice::Commitment(commitment), weight);
+            Ok(())
+        }
+
+        /// reveal the committed vote after the voting period.
+        /// * Only revealed votes are counted.
+        #[ink(message)]
+        pub fn reveal_vote(
+            &mut self,
+            dao_address: AccountId,
+            proposal_id: u128,
+            vote: VoteType,
+            salt: Vec<u8>,
+        ) -> Result<()> {
+            let caller = self.env().caller();
+            let proposal_info: ProposalInfo =
+                match self.proposal_infoes.get(&(dao_address, proposal_id)) {
+                    Some(value) => value,
+                    None => return Err(Error::ProposalDoesNotExist),
+                };
+            if !proposal_info.is_secret_ballot {
+                return Err(Error::InvalidVotingMethod);
+            }
+            if proposal_info.status != ProposalStatus::Voting {
+                return Err(Error::IncorrectVotingStatus);
+            }
+            let now = self.env().block_timestamp();
+            if now < proposal_info.voting_end_time || proposal_info.reveal_end_time <= now {
+                return Err(Error::OutOfRevealPeriod);
+            }
+
+            let (commitment, weight) = match self.ballots.get(&(dao_address, proposal_id, caller)) {
+                Some(Ballot {
+                    choice: BallotChoice::Commitment(value),
+                    weight,
+                    ..
+                }) => (value, weight),
+                _ => return Err(Error::CommitmentDoesNotExist),
+            };
+            if commitment != self.get_vote_commitment(dao_address, proposal_id, caller, vote.clone(), salt) {
+                return Err(Error::InvalidCommitment);
+            }
+
+            let mut vote_result: VotingResult =
+                match self.voting_results.get(&(dao_address, proposal_id)) {
+                    Some(value) => value,
+                    None => VotingResult {
+                        proposal_id: proposal_id,
+                        yes: 0,
+                        no: 0,
+                        abstain: 0,
+                        delegated: 0,
+                    },
+                };
+            self.inline_record_ballot(dao_address, proposal_info.clone(), caller, BallotChoice::Vote(vote.clone()), weight);
+            vote_result = self.inline_add_vote(vote_result, vote, weight);
+            self.voting_results
+                .insert(&(dao_address, proposal_id), &vote_result);
+            Ok(())
+        }
+
+        /// get the commitment of the secret vote.
+        /// * blake2x256 hash of ( dao address, proposal_id, voter, vote, salt ).
+        /// * Call this function by dry-run so that the vote is not published.
+        #[ink(message)]
+        pub fn get_vote_commitment(
+            &self,
+            dao_address: AccountId,
+            proposal_id: u128,
+            voter: AccountId,
+            vote: VoteType,
+            salt: Vec<u8>,
+        ) -> Hash {
+            let mut output = <ink_env::hash::Blake2x256 as ink_env::hash::HashOutput>::Type::default();
+            ink_env::hash_encoded::<ink_env::hash::Blake2x256, _>(
+                &(dao_address, proposal_id, voter, vote, salt),
+                &mut output,
+            );
+            Hash::from(output)
+        }
+
+        /// get the ballot of the voter.
+        #[ink(message)]
+        pub fn get_ballot(&self, dao_address: AccountId, proposal_id: u128, voter: AccountId) -> Option<Ballot> {
+            self.ballots.get(&(dao_address, proposal_id, voter))
+        }
+
+        /// get ballots of the proposal for audit.
+        /// * At most limit ( up to MAX_PAGE_SIZE ) ballots are returned from the offset in order of voting.
+        #[ink(message)]
+        pub fn get_ballots(
+            &self,
+            dao_address: AccountId,
+            proposal_id: u128,
+            offset: u32,
+            limit: u32,
+        ) -> Vec<(AccountId, Ballot)> {
+            let mut ballot_list: Vec<(AccountId, Ballot)> = Vec::new();
+            let limit = match limit > MAX_PAGE_SIZE {
+                true => MAX_PAGE_SIZE,
+                false => limit,
+            };
+            let ballot_count = self.get_ballot_count(dao_address, proposal_id);
+            for i in offset..ballot_count {
+                if ballot_list.len() >= limit as usize {
+                    break;
+                }
+                let voter = match self.ballot_voters.get(&(dao_address, proposal_id, i)) {
+                    Some(value) => value,
+                    None => continue,
+                };
+                match self.ballots.get(&(dao_address, proposal_id, voter)) {
+                    Some(value) => ballot_list.push((voter, value)),
+                    None => continue,
+                }
+            }
+            ballot_list
+        }
+
+        /// get the count of ballots of the proposal.
+        #[ink(message)]
+        pub fn get_ballot_count(&self, dao_address: AccountId, proposal_id: u128) -> u32 {
+            match self.ballot_counts.get(&(dao_address, proposal_id)) {
+                Some(value) => value,
+                None => 0,
+            }
+        }
+
+        /// get voting result
+        #[ink(message)]
+        pub fn get_voted_result(
+            &self,
+            _dao_address: AccountId,
+            _proposal_id: u128,
+        ) -> Option<VotingResult> {
+            self.voting_results.get(&(_dao_address, _proposal_id))
+        }
+
+        /// delegate the caller's vote to the other member.
+        /// * The delegate casts the vote of the caller unless the caller votes directly.
+        /// * For each proposal, the delegation is frozen when the delegate votes.
+        #[ink(message)]
+        pub fn delegate_vote(&mut self, dao_address: AccountId, delegate: AccountId) -> Result<()> {
+            let caller = self.env().caller();
+            if self
+                .member_manager
+                .modifier_only_member(caller, dao_address)
+                == false
+            {
+                return Err(Error::OnlyMemberDoes);
+            }
+            if caller == delegate
+                || self
+                    .member_manager
+                    .modifier_only_member(delegate, dao_address)
+                    == false
+            {
+                return Err(Error::InvalidDelegate);
+            }
+
+            match self.delegations.get(&(dao_address, caller)) {
+                Some(_value) => self.inline_revoke_delegation(dao_address, caller),
+                None => (),
+            }
+            self.delegations.insert(&(dao_address, caller), &delegate);
+            let mut delegator_list = self.get_delegators(dao_address, delegate);
+            delegator_list.push(caller);
+            self.delegators.insert(&(dao_address, delegate), &delegator_list);
+            Ok(())
+        }
+
+        /// revoke the caller's delegation.
+        #[ink(message)]
+        pub fn revoke_delegation(&mut self, dao_address: AccountId) -> Result<()> {
+            let caller = self.env().caller();
+            match self.delegations.get(&(dao_address, caller)) {
+                Some(_value) => self.inline_revoke_delegation(dao_address, caller),
+                None => return Err(Error::DelegationDoesNotExist),
+            }
+            Ok(())
+        }
+
+        /// get the delegate of the delegator.
+        #[ink(message)]
+        pub fn get_delegate(&self, dao_address: AccountId, delegator: AccountId) -> Option<AccountId> {
+            self.delegations.get(&(dao_address, delegator))
+        }
+
+        /// get the delegators whom the delegate represents.
+        #[ink(message)]
+        pub fn get_delegators(&self, dao_address: AccountId, delegate: AccountId) -> Vec<AccountId> {
+            match self.delegators.get(&(dao_address, delegate)) {
+                Some(value) => value,
+                None => Vec::new(),
+            }
+        }
+
+        /// get the list of who represents whom.
+        #[ink(message)]
+        pub fn get_delegation_list(&self, dao_address: AccountId) -> Vec<DelegationInfo> {
+            let mut delegation_list: Vec<DelegationInfo> = Vec::new();
+            for member_info in self.member_manager.get_member_list(dao_address) {
+                let delegator = member_info.member_address();
+                match self.delegations.get(&(dao_address, delegator)) {
+                    Some(value) => delegation_list.push(DelegationInfo {
+                        delegator: delegator,
+                        delegate: value,
+                    }),
+                    None => continue,
+                }
+            }
+            delegation_list
+        }
+
+        /// change the proposal status
+        /// * FinishVoting is rejected until the voting period ( and the reveal period of the secret ballot ) finishes.
+        #[ink(message)]
+        pub fn change_proposal_status(
+            &mut self,
+            _dao_address: AccountId,
+            _proposal_id: u128,
+            _status: ProposalStatus,
+        ) -> Result<()> {
+            let caller = self.env().caller();
+            if self
+                .member_manager
+                .modifier_only_electoral_commissioner(caller, _dao_address)
+                == false
+            {
+                return Err(Error::OnlyElectoralCommissioner);
+            }
+
+            let mut proposal_info: ProposalInfo =
+                match self.proposal_infoes.get(&(_dao_address, _proposal_id)) {
+                    Some(value) => value,
+                    None => return Err(Error::ProposalDoesNotExist),
+                };
+
+            let limit = self.is_tenure_of_electoral_commissioner_expired(_dao_address);
+            match proposal_info.proposal_type {
+                ProposalType::ChangeElectoralCommissioner => match limit {
+                    true => (),
+                    false => return Err(Error::NotExpirationOfTermOfElectionCommissioner),
+                },
+                // the recall is allowed at any time.
+                ProposalType::RecallElectoralCommissioner => (),
+                _ => match limit {
+                    true => return Err(Error::ExpirationOfTermOfElectionCommissioner),
+                    false => (),
+                },
+            };
+
+            // the voting can not be closed before the voting period finishes.
+            if _status == ProposalStatus::FinishVoting
+                && self.env().block_timestamp() < proposal_info.voting_end_time
+            {
+                return Err(Error::VotingPeriodNotFinished);
+            }
+            if _status == ProposalStatus::FinishVoting
+                && proposal_info.is_secret_ballot
+                && self.env().block_timestamp() < proposal_info.reveal_end_time
+            {
+                return Err(Error::RevealPeriodNotFinished);
+            }
+
+            match self.check_anti_pattern(proposal_info.clone(), _status.clone()) {
+                true => match _status {
+                    ProposalStatus::Voting => {
+                        self.inline_open_voting(_dao_address, proposal_info);
+                    }
+                    _ => {
+                        proposal_info.status = _status.clone();
+                        self.inline_change_proposal_status(_dao_address, proposal_info.clone())
+                    }
+                },
+                false => return Err(Error::InvalidChanging),
+            }
+            if _status == ProposalStatus::FinishVoting {
+                match self.count_votes_of_proposal(_dao_address, _proposal_id) {
+                    Ok(()) => (),
+                    Err(e) => return Err(e),
+                };
+            }
+            Ok(())
+        }
+
+        /// cancel the proposal.
+        /// * Only the proposer can cancel the proposal before the voting period finishes.
+        #[ink(message)]
+        pub fn cancel_proposal(
+            &mut self,
+            _dao_address: AccountId,
+            _proposal_id: u128,
+        ) -> Result<()> {
+            let caller = self.env().caller();
+            let mut proposal_info: ProposalInfo =
+                match self.proposal_infoes.get(&(_dao_address, _proposal_id)) {
+                    Some(value) => value,
+                    None => return Err(Error::ProposalDoesNotExist),
+                };
+            if proposal_info.proposer != caller {
+                return Err(Error::OnlyProposerDoes);
+            }
+            match proposal_info.status {
+                ProposalStatus::Proposed | ProposalStatus::Voting => (),
+                _ => return Err(Error::CanNotCancel),
+            }
+            if self.env().block_timestamp() >= proposal_info.voting_end_time {
+                return Err(Error::CanNotCancel);
+            }
+
+            proposal_info.status = ProposalStatus::Cancelled;
+            self.inline_change_proposal_status(_dao_address, proposal_info.clone());
+            self.inline_settle_deposit(_dao_address, proposal_info, false);
+            Ok(())
+        }
+
+        /// finalize the proposal whose voting period has finished.
+        /// * Anyone can call this function.
+        #[ink(message)]
+        pub fn finalize_proposal(
+            &mut self,
+            _dao_address: AccountId,
+            _proposal_id: u128,
+        ) -> Result<()> {
+            let mut proposal_info: ProposalInfo =
+                match self.proposal_infoes.get(&(_dao_address, _proposal_id)) {
+                    Some(value) => value,
+                    None => return Err(Error::ProposalDoesNotExist),
+                };
+            match proposal_info.status {
+                ProposalStatus::Proposed | ProposalStatus::Voting => (),
+                _ => return Err(Error::IncorrectVotingStatus),
+            }
+            if self.env().block_timestamp() < proposal_info.voting_end_time {
+                return Err(Error::VotingPeriodNotFinished);
+            }
+            if self.env().block_timestamp() < proposal_info.reveal_end_time {
+                return Err(Error::RevealPeriodNotFinished);
+            }
+
+            // the optimistic proposal which is not objected passes without the vote.
+            if proposal_info.is_optimistic {
+                proposal_info.status = ProposalStatus::Running;
+                proposal_info = self.inline_set_execution_schedule(_dao_address, proposal_info);
+                self.inline_change_proposal_status(_dao_address, proposal_info.clone());
+                self.inline_settle_deposit(_dao_address, proposal_info, false);
+                return Ok(());
+            }
+            if proposal_info.status == ProposalStatus::Proposed {
+                proposal_info = self.inline_open_voting(_dao_address, proposal_info);
+            }
+            proposal_info.status = ProposalStatus::FinishVoting;
+            self.inline_change_proposal_status(_dao_address, proposal_info.clone());
+            self.count_votes_of_proposal(_dao_address, _proposal_id)
+        }
+
+        /// veto the proposal during the timelock period.
+        /// * Only electoral commissioners can veto, and the reason is required.
+        /// * Commissioners whose term has expired can not veto, and the change or the recall of commissioners can not be vetoed.
+        /// * The proposal is Vetoed when the count of vetoes reaches the veto threshold.
+        #[ink(message)]
+        pub fn veto_proposal(
+            &mut self,
+            _dao_address: AccountId,
+            _proposal_id: u128,
+            _reason: String,
+        ) -> Result<()> {
+            let caller = self.env().caller();
+            if self
+                .member_manager
+                .modifier_only_electoral_commissioner(caller, _dao_address)
+                == false
+            {
+                return Err(Error::OnlyElectoralCommissioner);
+            }
+            if _reason.len() == 0 {
+                return Err(Error::VetoReasonIsRequired);
+            }
+
+            let mut proposal_info: ProposalInfo =
+                match self.proposal_infoes.get(&(_dao_address, _proposal_id)) {
+                    Some(value) => value,
+                    None => return Err(Error::ProposalDoesNotExist),
+                };
+            if proposal_info.status != ProposalStatus::Running {
+                return Err(Error::NotRunning);
+            }
+            if self.env().block_timestamp() >= proposal_info.executable_time {
+                return Err(Error::InvalidChanging);
+            }
+            // electoral commissioners can not protect themselves from the change or the recall.
+            match proposal_info.proposal_type {
+                ProposalType::ChangeElectoralCommissioner | ProposalType::RecallElectoralCommissioner => {
+                    return Err(Error::CommissionerChangeCanNotBeVetoed)
+                }
+                _ => (),
+            }
+            // electoral commissioners whose term has expired can not veto.
+            if self.is_tenure_of_electoral_commissioner_expired(_dao_address) {
+                return Err(Error::ExpirationOfTermOfElectionCommissioner);
+            }
+
+            let mut veto_list = self.get_vetoes(_dao_address, _proposal_id);
+            for veto in veto_list.iter() {
+                if veto.electoral_commissioner == caller {
+                    return Err(Error::AlreadyVetoed);
+                }
+            }
+            veto_list.push(VetoInfo {
+                electoral_commissioner: caller,
+                reason: _reason,
+                timestamp: self.env().block_timestamp(),
+            });
+            self.vetoes.insert(&(_dao_address, _proposal_id), &veto_list);
+
+            if veto_list.len() >= self.get_governance_config(_dao_address).veto_threshold as usize {
+                proposal_info.status = ProposalStatus::Vetoed;
+                self.inline_change_proposal_status(_dao_address, proposal_info);
+            }
+            Ok(())
+        }
+
+        /// get vetoes of the proposal.
+        #[ink(message)]
+        pub fn get_vetoes(&self, _dao_address: AccountId, _proposal_id: u128) -> Vec<VetoInfo> {
+            match self.vetoes.get(&(_dao_address, _proposal_id)) {
+                Some(value) => value,
+                None => Vec::new(),
+            }
+        }
+
+        /// start the election of electoral commissioners.
+        /// * Any member can start the election after the tenure of electoral commissioners expires.
+        /// * The count of seats is the election seats of the DAO, and it is limited to the count of members.
+        /// * The nomination period starts now and the voting period follows it.
+        #[ink(message)]
+        pub fn start_election(&mut self, dao_address: AccountId) -> Result<()> {
+            let caller = self.env().caller();
+            if self
+                .member_manager
+                .modifier_only_member(caller, dao_address)
+                == false
+            {
+                return Err(Error::OnlyMemberDoes);
+            }
+            if !self.is_tenure_of_electoral_commissioner_expired(dao_address) {
+                return Err(Error::NotExpirationOfTermOfElectionCommissioner);
+            }
+            let config = self.get_governance_config(dao_address);
+            let electorate_size = self.member_manager.get_member_list(dao_address).len() as u32;
+            if electorate_size == 0 {
+                return Err(Error::InvalidSeats);
+            }
+            let seats = match config.election_seats as u32 > electorate_size {
+                true => electorate_size as u16,
+                false => config.election_seats,
+            };
+
+            let mut next_election_id = match self.next_election_ids.get(&dao_address) {
+                Some(value) => value,
+                None => 0,
+            };
+            if next_election_id > 0 {
+                match self.elections.get(&(dao_address, next_election_id - 1)) {
+                    Some(value) if value.status == ElectionStatus::InProgress => {
+                        return Err(Error::ElectionIsInProgress)
+                    }
+                    _ => (),
+                }
+            }
+
+            let nomination_end_time = self.env().block_timestamp() + config.nomination_period;
+            let election_info = ElectionInfo {
+                election_id: next_election_id,
+                seats: seats,
+                status: ElectionStatus::InProgress,
+                nomination_end_time: nomination_end_time,
+                voting_end_time: nomination_end_time + config.voting_period,
+                snapshot_block: self.get_snapshot_block(),
+                membership_epoch: self.member_manager.get_membership_epoch(dao_address),
+                electorate_size: electorate_size,
+                voting_mode: config.voting_mode.clone(),
+                governance_token: config.governance_token,
+            };
+            self.elections.insert(&(dao_address, next_election_id), &election_info);
+            next_election_id = next_election_id + 1;
+            self.next_election_ids.insert(&dao_address, &next_election_id);
+            Ok(())
+        }
+
+        /// nominate the member as the candidate of the election.
+        /// * A member can nominate oneself or the other member during the nomination period.
+        #[ink(message)]
+        pub fn nominate_candidate(
+            &mut self,
+            dao_address: AccountId,
+            election_id: u128,
+            candidate: AccountId,
+        ) -> Result<()> {
+            let caller = self.env().caller();
+            if self
+                .member_manager
+                .modifier_only_member(caller, dao_address)
+                == false
+            {
+                return Err(Error::OnlyMemberDoes);
+            }
+            if self
+                .member_manager
+                .modifier_only_member(candidate, dao_address)
+                == false
+            {
+                return Err(Error::InvalidCandidates);
+            }
+            let election_info = match self.elections.get(&(dao_address, election_id)) {
+                Some(value) => value,
+                None => return Err(Error::ElectionDoesNotExist),
+            };
+            if election_info.status != ElectionStatus::InProgress
+                || self.env().block_timestamp() >= election_info.nomination_end_time
+            {
+                return Err(Error::OutOfNominationPeriod);
+            }
+
+            let mut candidate_list = self.get_candidate_list(dao_address, election_id);
+            if candidate_list.contains(&candidate) {
+                return Err(Error::CandidateAlreadyNominated);
+            }
+            if candidate_list.len() >= MAX_CANDIDATES {
+                return Err(Error::TooManyCandidates);
+            }
+            candidate_list.push(candidate);
+            self.candidates.insert(&(dao_address, election_id), &candidate_list);
+            Ok(())
+        }
+
+        /// vote for up to seats candidates of the election.
+        /// * Only members who joined by the start of the election can vote once during the voting period.
+        #[ink(message)]
+        pub fn vote_for_candidates(
+            &mut self,
+            dao_address: AccountId,
+            election_id: u128,
+            candidates: Vec<AccountId>,
+        ) -> Result<()> {
+            let caller = self.env().caller();
+            let election_info = match self.elections.get(&(dao_address, election_id)) {
+                Some(value) => value,
+                None => return Err(Error::ElectionDoesNotExist),
+            };
+            if self
+                .member_manager
+                .modifier_only_member_at_epoch(caller, dao_address, election_info.membership_epoch)
+                == false
+            {
+                return Err(Error::NotEligibleVoter);
+            }
+            let now = self.env().block_timestamp();
+            if election_info.status != ElectionStatus::InProgress
+                || now < election_info.nomination_end_time
+                || election_info.voting_end_time <= now
+            {
+                return Err(Error::OutOfVotingPeriod);
+            }
+            match self.election_ballots.get(&(dao_address, election_id, caller)) {
+                Some(_value) => return Err(Error::AlreadyVoted),
+                None => (),
+            }
+
+            let candidate_list = self.get_candidate_list(dao_address, election_id);
+            if candidates.len() == 0 || candidates.len() > election_info.seats as usize {
+                return Err(Error::InvalidCandidates);
+            }
+            for i in 0..candidates.len() {
+                if !candidate_list.contains(&candidates[i]) || candidates[..i].contains(&candidates[i]) {
+                    return Err(Error::InvalidCandidates);
+                }
+            }
+
+            let weight = match self.get_vote_weight(
+                caller,
+                election_info.snapshot_block,
+                &election_info.voting_mode,
+                election_info.governance_token,
+            ) {
+                Ok(value) => value,
+                Err(e) => return Err(e),
+            };
+            for candidate in candidates.iter() {
+                let votes = match self.candidate_votes.get(&(dao_address, election_id, *candidate)) {
+                    Some(value) => value,
+                    None => 0,
+                };
+                self.candidate_votes
+                    .insert(&(dao_address, election_id, *candidate), &(votes + weight));
+            }
+            let turnout = match self.election_turnouts.get(&(dao_address, election_id)) {
+                Some(value) => value,
+                None => 0,
+            };
+            self.election_turnouts
+                .insert(&(dao_address, election_id), &(turnout + weight));
+            self.election_ballots
+                .insert(&(dao_address, election_id, caller), &candidates);
+            Ok(())
+        }
+
+        /// finalize the election whose voting period has finished.
+        /// * Anyone can call this function.
+        /// * The candidates who have the most votes become electoral commissioners up to seats.
+        /// * The election fails when the required voter turnout is not achieved, nobody who is still a member gets a vote,
+        ///   or the member manager rejects the winners.
+        #[ink(message)]
+        pub fn finalize_election(&mut self, dao_address: AccountId, election_id: u128) -> Result<()> {
+            let mut election_info = match self.elections.get(&(dao_address, election_id)) {
+                Some(value) => value,
+                None => return Err(Error::ElectionDoesNotExist),
+            };
+            if election_info.status != ElectionStatus::InProgress {
+                return Err(Error::IncorrectVotingStatus);
+            }
+            if self.env().block_timestamp() < election_info.voting_end_time {
+                return Err(Error::VotingPeriodNotFinished);
+            }
+
+            let total_weight = self.get_total_vote_weight(
+                election_info.snapshot_block,
+                election_info.electorate_size,
+                &election_info.voting_mode,
+                election_info.governance_token,
+            );
+            let turnout = match self.election_turnouts.get(&(dao_address, election_id)) {
+                Some(value) => value,
+                None => 0,
+            };
+            let config = self.get_governance_config(dao_address);
+            let winners = self.get_election_winners(dao_address, election_info.clone());
+            if total_weight == 0
+                || (turnout * 100 / total_weight) < config.required_voter_turnout_percentage as Balance
+                || winners.len() == 0
+            {
+                election_info.status = ElectionStatus::Failed;
+                self.elections.insert(&(dao_address, election_id), &election_info);
+                return Ok(());
+            }
+
+            // the election fails instead of staying InProgress so that the next election can be started.
+            election_info.status = match self.member_manager.elect_electoral_commissioners(dao_address, winners) {
+                Ok(()) => ElectionStatus::Finished,
+                Err(_e) => ElectionStatus::Failed,
+            };
+            self.elections.insert(&(dao_address, election_id), &election_info);
+            Ok(())
+        }
+
+        /// get the election info.
+        #[ink(message)]
+        pub fn get_election(&self, dao_address: AccountId, election_id: u128) -> Option<ElectionInfo> {
+            self.elections.get(&(dao_address, election_id))
+        }
+
+        /// get the candidates of the election with their votes in order of nomination.
+        #[ink(message)]
+        pub fn get_candidates(&self, dao_address: AccountId, election_id: u128) -> Vec<CandidateInfo> {
+            let mut candidate_info_list: Vec<CandidateInfo> = Vec::new();
+            for candidate in self.get_candidate_list(dao_address, election_id) {
+                let votes = match self.candidate_votes.get(&(dao_address, election_id, candidate)) {
+                    Some(value) => value,
+                    None => 0,
+                };
+                candidate_info_list.push(CandidateInfo {
+                    candidate: candidate,
+                    votes: votes,
+                });
+            }
+            candidate_info_list
+        }
+
+        /// execute the proposal
+        /// * Each attempt is recorded with the error of the callee, and the proposal can be executed again while it is Running.
+        /// * The proposal becomes ExecutionFailed when the attempts reach the max execution attempts of the DAO.
+        /// * The failed proposal can be executed again after the retry interval of the DAO.
+        /// * The actions of the composite proposal are executed by the call to this contract,
+        ///   so all of them are reverted if one of them fails while the failed attempt is recorded.
+        #[ink(message)]
+        pub fn execute_proposal(
+            &mut self,
+            _dao_address: AccountId,
+            _proposal_id: u128,
+        ) -> Result<()> {
+            let caller = self.env().caller();
+            if self
+                .member_manager
+                .modifier_only_member(caller, _dao_address)
+                == false
+            {
+                ink_env::debug_println!("########################### OnlyMemberDoes Error.");
+                return Err(Error::OnlyMemberDoes);
+            }
+
+            let mut proposal_info: ProposalInfo =
+                match self.proposal_infoes.get(&(_dao_address, _proposal_id)) {
+                    Some(value) => {
+                        if value.status != ProposalStatus::Running {
+                            ink_env::debug_println!("########################### NotRunning Error.");
+                            return Err(Error::NotRunning);
+                        }
+                        value
+                    }
+                    None => {
+                        ink_env::debug_println!("########################### ProposalDoesNotExist Error.");
+                        return Err(Error::ProposalDoesNotExist);
+                    },
+                };
+            if self.env().block_timestamp() > proposal_info.execution_deadline {
+                ink_env::debug_println!("########################### ExecutionDeadlinePassed Error.");
+                proposal_info.status = ProposalStatus::Expired;
+                self.inline_change_proposal_status(_dao_address, proposal_info);
+                return Err(Error::ExecutionDeadlinePassed);
+            }
+            if self.env().block_timestamp() < proposal_info.executable_time {
+                ink_env::debug_println!("########################### TimelockNotElapsed Error.");
+                return Err(Error::TimelockNotElapsed);
+            }
+
+            // the failed proposal can not be executed again until the retry interval elapses.
+            let attempt_count = match self.execution_attempt_counts.get(&(_dao_address, _proposal_id)) {
+                Some(value) => value,
+                None => 0,
+            };
+            match self.execution_attempts.get(&(_dao_address, _proposal_id, attempt_count)) {
+                Some(value)
+                    if self.env().block_timestamp()
+                        < value.timestamp + self.get_governance_config(_dao_address).execution_retry_interval =>
+                {
+                    return Err(Error::RetryIntervalNotElapsed)
+                }
+                _ => (),
+            }
+
+            let result = match proposal_info.proposal_type {
+                ProposalType::RankedChoice => {
+                    let options = self.get_proposal_options(_dao_address, _proposal_id);
+                    match self.winning_options.get(&(_dao_address, _proposal_id)) {
+                        Some(value) if (value as usize) < options.len() => self.inline_execute_action(
+                            _dao_address,
+                            options[value as usize].proposal_type.clone(),
+                            options[value as usize].csv_data.clone(),
+                        ),
+                        _ => return Err(Error::WinningOptionDoesNotExist),
+                    }
+                }
+                ProposalType::Composite => self.inline_call_composite_actions(_dao_address, _proposal_id),
+                _ => self.inline_execute_action(
+                    _dao_address,
+                    proposal_info.proposal_type.clone(),
+                    proposal_info.csv_data.clone(),
+                ),
+            };
+            match result {
+                Ok(()) => (),
+                Err(e) => return self.inline_fail_execution(_dao_address, proposal_info, e),
+            }
+            self.inline_record_execution_attempt(_dao_address, _proposal_id, None);
+            proposal_info.status = ProposalStatus::Finished;
+            self.inline_change_proposal_status(_dao_address, proposal_info.clone());
+            Ok(())
+        }
+
+        /// execute the actions of the composite proposal.
+        /// * This function can be called only by this contract from execute_proposal.
+        /// * The actions are executed in order, and all of them are reverted if one of them fails.
+        #[ink(message, selector = 0x434f4d50)]
+        pub fn execute_composite_actions(
+            &mut self,
+            _dao_address: AccountId,
+            _proposal_id: u128,
+        ) -> core::result::Result<(), ExecutionError> {
+            if self.env().caller() != self.env().account_id() {
+                return Err(ExecutionError::ProposalManager(Error::OnlyThisContractDoes));
+            }
+            for action in self.get_proposal_actions(_dao_address, _proposal_id) {
+                match self.inline_execute_action(_dao_address, action.proposal_type, action.csv_data) {
+                    Ok(()) => (),
+                    Err(e) => {
+                        // returning an error does not revert the storage,
+                        // so revert the actions which are already executed and return the error to the caller.
+                        ink_env::return_value::<core::result::Result<(), ExecutionError>>(
+                            ink_env::ReturnFlags::default().set_reverted(true),
+                            &Err(e),
+                        );
+                    }
+                };
+            }
+            Ok(())
+        }
+
+        /// call execute_composite_actions of this contract.
+        /// * The reverted call returns the error of the callee, and the storage of this call is kept.
+        fn inline_call_composite_actions(
+            &mut self,
+            _dao_address: AccountId,
+            _proposal_id: u128,
+        ) -> core::result::Result<(), ExecutionError> {
+            match build_call::<ink_env::DefaultEnvironment>()
+                .call_type(Call::new().callee(self.env().account_id()).gas_limit(0))
+                .call_flags(ink_env::CallFlags::default().set_allow_reentry(true))
+                .exec_input(
+                    ExecutionInput::new(Selector::new(EXECUTE_COMPOSITE_ACTIONS_SELECTOR))
+                        .push_arg(_dao_address)
+                        .push_arg(_proposal_id),
+                )
+                .returns::<core::result::Result<(), ExecutionError>>()
+                .fire()
+            {
+                Ok(value) => value,
+                Err(_e) => Err(ExecutionError::ProposalManager(Error::CompositeCallIsFailure)),
+            }
+        }
+
+        /// record the failed execution attempt and return the error.
+        /// * The proposal becomes ExecutionFailed when the attempts reach the max execution attempts of the DAO.
+        fn inline_fail_execution(
+            &mut self,
+            _dao_address: AccountId,
+            mut _proposal_info: ProposalInfo,
+            _error: ExecutionError,
+        ) -> Result<()> {
+            let attempt =
+                self.inline_record_execution_attempt(_dao_address, _proposal_info.proposal_id, Some(_error.clone()));
+            if attempt >= self.get_governance_config(_dao_address).max_execution_attempts {
+                _proposal_info.status = ProposalStatus::ExecutionFailed;
+                self.inline_change_proposal_status(_dao_address, _proposal_info);
+            }
+            Err(self.convert_execution_error(_error))
+        }
+
+        /// convert the error of the callee to the error of this contract.
+        #[inline]
+        fn convert_execution_error(&self, _error: ExecutionError) -> Error {
+            match _error {
+                ExecutionError::MemberManager(_e) => Error::InvalidMemberManagerCall,
+                ExecutionError::DaoManager(_e) => Error::InvalidDaoManagerCall,
+                ExecutionError::ProposalManager(e) => e,
+            }
+        }
+
+        /// execute the action of the proposal type.
+        /// * The error of the callee is returned as it is.
+        fn inline_execute_action(
+            &mut self,
+            _dao_address: AccountId,
+            _proposal_type: ProposalType,
+            _csv_data: String,
+        ) -> core::result::Result<(), ExecutionError> {
+            match _proposal_type {
+                ProposalType::AddMember => {
+                    match self.member_manager.add_member(_dao_address, _csv_data) {
+                        Ok(()) => (),
+                        Err(e) => {
+                            ink_env::debug_println!("########################### Execute Error.");
+                            return Err(ExecutionError::MemberManager(e))
+                        },
+                    }
+                },
+                ProposalType::DeleteMember => {
+                    match self.member_manager.delete_member(_dao_address, _csv_data){
+                        Ok(()) => (),
+                        Err(e) => {
+                            ink_env::debug_println!("########################### Execute Error.");
+                            return Err(ExecutionError::MemberManager(e))
+                        },
+                    }
+                },
+                ProposalType::ChangeElectoralCommissioner => {
+                    match self.member_manager.change_electoral_commissioner(_dao_address,_csv_data,){
+                        Ok(()) => (),
+                        Err(e) => return Err(ExecutionError::MemberManager(e)),
+                    };
+                },
+                ProposalType::RecallElectoralCommissioner => {
+                    match self.member_manager.recall_electoral_commissioner(_dao_address, _csv_data) {
+                        Ok(()) => (),
+                        Err(e) => return Err(ExecutionError::MemberManager(e)),
+                    };
+                },
+                ProposalType::IssueToken => {
+                    match self.dao_manager.add_dao_token(_dao_address,_csv_data){
+                        Ok(()) => (),
+                        Err(e) => return Err(ExecutionError::DaoManager(e)),
+                    }
+                },
+                ProposalType::ChangeStatusOfTokenSale => {
+                    match self.dao_manager.change_token_sales_status(_dao_address,_csv_data){
+                        Ok(()) => (),
+                        Err(e) => return Err(ExecutionError::DaoManager(e)),
+                    }
+                },
+                ProposalType::WithdrawTokenSales => {
+                    match self.dao_manager.withdraw_token_proceeds(_dao_address,_csv_data){
+                        Ok(()) => (),
+                        Err(e) => return Err(ExecutionError::DaoManager(e)),
+                    }
+                },
+                ProposalType::DistributeGovernanceToken => {
+                    match self.dao_manager.distribute_governance_token(_dao_address,_csv_data){
+                        Ok(()) => (),
+                        Err(e) => return Err(ExecutionError::DaoManager(e)),
+                    }
+                },
+                ProposalType::UseDaoTresury => {
+                    match self.dao_manager.distribute_dao_treasury(_dao_address,_csv_data){
+                        Ok(()) => (),
+                        Err(e) => return Err(ExecutionError::DaoManager(e)),
+                    }
+                },
+                ProposalType::ExternalCall => {
+                    match self.dao_manager.execute_external_call(_dao_address,_csv_data){
+                        Ok(_output) => (),
+                        Err(e) => return Err(ExecutionError::DaoManager(e)),
+                    }
+                },
+                ProposalType::ChangeGovernanceParameters => {
+                    match self.change_governance_config(_dao_address, _csv_data) {
+                        Ok(()) => (),
+                        Err(e) => return Err(ExecutionError::ProposalManager(e)),
+                    }
+                },
+                ProposalType::RankedChoice => return Err(ExecutionError::ProposalManager(Error::InvalidProposalOptions)),
+                ProposalType::Composite => return Err(ExecutionError::ProposalManager(Error::InvalidProposalActions)),
+            };
+            Ok(())
+        }
+
+        /// get the execution attempts of the proposal in order.
+        #[ink(message)]
+        pub fn get_execution_attempts(&self, _dao_address: AccountId, _proposal_id: u128) -> Vec<ExecutionAttempt> {
+            let mut attempt_list: Vec<ExecutionAttempt> = Vec::new();
+            let attempt_count = match self.execution_attempt_counts.get(&(_dao_address, _proposal_id)) {
+                Some(value) => value,
+                None => 0,
+            };
+            for i in 1..=attempt_count {
+                match self.execution_attempts.get(&(_dao_address, _proposal_id, i)) {
+                    Some(value) => attempt_list.push(value),
+                    None => continue,
+                }
+            }
+            attempt_list
+        }
+
+        /// record the execution attempt and return the attempt number.
+        fn inline_record_execution_attempt(
+            &mut self,
+            _dao_address: AccountId,
+            _proposal_id: u128,
+            _error: Option<ExecutionError>,
+        ) -> u16 {
+            let attempt = match self.execution_attempt_counts.get(&(_dao_address, _proposal_id)) {
+                Some(value) => value + 1,
+                None => 1,
+            };
+            let execution_attempt = ExecutionAttempt {
+                attempt: attempt,
+                error: _error,
+                timestamp: self.env().block_timestamp(),
+            };
+            self.execution_attempts
+                .insert(&(_dao_address, _proposal_id, attempt), &execution_attempt);
+            self.execution_attempt_counts
+                .insert(&(_dao_address, _proposal_id), &attempt);
+            attempt
+        }
+
+        /// get governance config
+        #[ink(message)]
+        pub fn get_governance_config(&self, _dao_address: AccountId) -> GovernanceConfig {
+            match self.governance_configs.get(&_dao_address) {
+                Some(value) => value,
+                None => GovernanceConfig::default(),
+            }
+        }
+
+        /// check whether the tenure of electoral commissioners has expired.
+        #[ink(message)]
+        pub fn is_tenure_of_electoral_commissioner_expired(&self, _dao_address: AccountId) -> bool {
+            self.member_manager.is_tenure_expired(_dao_address)
+        }
+
+        /// change governance config
+        fn change_governance_config(&mut self, _dao_address: AccountId, _csv_data: String) -> Result<()> {
+            let config = match self.decode_governance_config(_dao_address, _csv_data) {
+                Ok(value) => value,
+                Err(e) => return Err(e),
+            };
+            if config.term_length != self.get_governance_config(_dao_address).term_length {
+                match self.member_manager.set_term_length(_dao_address, config.term_length) {
+                    Ok(()) => (),
+                    Err(_e) => return Err(Error::InvalidMemberManagerCall),
+                };
+            }
+            self.governance_configs.insert(&_dao_address, &config);
+            Ok(())
+        }
+
+        /// decode csv data of the governance config and return the changed config.
+        /// * csv_data: "parameter_name#value?parameter_name#value?..."
+        /// * parameter_name: quorum, majority, term_length, voting_period, reveal_period, execution_period,
+        ///   timelock_period, veto_threshold, proposal_deposit,
+        ///   voting_mode ("0": one person one vote, "1": token weighted), governance_token,
+        ///   emergency_voting_period, emergency_majority,
+        ///   emergency_proposal_types ( proposal type names separated by "/", e.g. "DeleteMember/AddMember" ),
+        ///   max_execution_attempts, execution_retry_interval, nomination_period, election_seats, recall_majority,
+        ///   optimistic_proposal_types ( proposal type names separated by "/" ), challenge_period,
+        ///   objection_threshold, optimistic_treasury_limit
+        /// * emergency_voting_period has to be shorter than voting_period, and emergency_majority and
+        ///   recall_majority can not be lower than majority.
+        fn decode_governance_config(&self, _dao_address: AccountId, _csv_data: String) -> Result<GovernanceConfig> {
+            let mut config = self.get_governance_config(_dao_address);
+            let lines: Vec<&str> = _csv_data.split('?').collect();
+            for line in lines {
+                let part_data: Vec<&str> = line.split('#').collect();
+                if part_data.len() != 2 {
+                    return Err(Error::InvalidGovernanceParameters);
+                }
+                match part_data[0] {
+                    "quorum" => match part_data[1].parse::<u16>() {
+                        Ok(value) if 0 < value && value <= 100 => config.required_voter_turnout_percentage = value,
+                        _ => return Err(Error::InvalidGovernanceParameters),
+                    },
+                    "majority" => match part_data[1].parse::<u16>() {
+                        Ok(value) if 0 < value && value <= 100 => config.majority_percentage = value,
+                        _ => return Err(Error::InvalidGovernanceParameters),
+                    },
+                    "term_length" => match part_data[1].parse::<u64>() {
+                        Ok(value) if 0 < value => config.term_length = value,
+                        _ => return Err(Error::InvalidGovernanceParameters),
+                    },
+                    "voting_period" => match part_data[1].parse::<u64>() {
+                        Ok(value) if 0 < value => config.voting_period = value,
+                        _ => return Err(Error::InvalidGovernanceParameters),
+                    },
+                    "reveal_period" => match part_data[1].parse::<u64>() {
+                        Ok(value) if 0 < value => config.reveal_period = value,
+                        _ => return Err(Error::InvalidGovernanceParameters),
+                    },
+                    "execution_period" => match part_data[1].parse::<u64>() {
+                        Ok(value) if 0 < value => config.execution_period = value,
+                        _ => return Err(Error::InvalidGovernanceParameters),
+                    },
+                    "timelock_period" => match part_data[1].parse::<u64>() {
+                        Ok(value) => config.timelock_period = value,
+                        _ => return Err(Error::InvalidGovernanceParameters),
+                    },
+                    "veto_threshold" => match part_data[1].parse::<u16>() {
+                        Ok(value) if 0 < value => config.veto_threshold = value,
+                        _ => return Err(Error::InvalidGovernanceParameters),
+                    },
+                    "proposal_deposit" => match part_data[1].parse::<Balance>() {
+                        Ok(value) => config.proposal_deposit = value,
+                        _ => return Err(Error::InvalidGovernanceParameters),
+                    },
+                    "voting_mode" => match part_data[1] {
+                        "0" => config.voting_mode = VotingMode::OnePersonOneVote,
+                        "1" => config.voting_mode = VotingMode::TokenWeighted,
+                        _ => return Err(Error::InvalidGovernanceParameters),
+                    },
+                    "governance_token" => match self.convert_string_to_accountid(part_data[1]) {
+                        Some(value) => config.governance_token = Some(value),
+                        None => return Err(Error::InvalidGovernanceParameters),
+                    },
+                    "emergency_voting_period" => match part_data[1].parse::<u64>() {
+                        Ok(value) if 0 < value => config.emergency_voting_period = value,
+                        _ => return Err(Error::InvalidGovernanceParameters),
+                    },
+                    "emergency_majority" => match part_data[1].parse::<u16>() {
+                        Ok(value) if 0 < value && value <= 100 => config.emergency_majority_percentage = value,
+                        _ => return Err(Error::InvalidGovernanceParameters),
+                    },
+                    "challenge_period" => match part_data[1].parse::<u64>() {
+                        Ok(value) if 0 < value => config.challenge_period = value,
+                        _ => return Err(Error::InvalidGovernanceParameters),
+                    },
+                    "objection_threshold" => match part_data[1].parse::<u16>() {
+                        Ok(value) if 0 < value => config.objection_threshold = value,
+                        _ => return Err(Error::InvalidGovernanceParameters),
+                    },
+                    "optimistic_treasury_limit" => match part_data[1].parse::<Balance>() {
+                        Ok(value) => config.optimistic_treasury_limit = value,
+                        _ => return Err(Error::InvalidGovernanceParameters),
+                    },
+                    "optimistic_proposal_types" => {
+                        let mut proposal_types: Vec<ProposalType> = Vec::new();
+                        for type_name in part_data[1].split('/') {
+                            if type_name.len() == 0 {
+                                continue;
+                            }
+                            match self.convert_string_to_proposal_type(type_name) {
+                                // the change of commissioners or rules, the distribution of the governance token
+                                // and the external call which can move any asset of the DAO always require the vote.
+                                Some(ProposalType::ChangeElectoralCommissioner)
+                                | Some(ProposalType::RecallElectoralCommissioner)
+                                | Some(ProposalType::ChangeGovernanceParameters)
+                                | Some(ProposalType::DistributeGovernanceToken)
+                                | Some(ProposalType::ExternalCall) => {
+                                    return Err(Error::InvalidGovernanceParameters)
+                                }
+                                Some(value) => proposal_types.push(value),
+                                None => return Err(Error::InvalidGovernanceParameters),
+                            }
+                        }
+                        config.optimistic_proposal_types = proposal_types;
+                    }
+                    "recall_majority" => match part_data[1].parse::<u16>() {
+                        Ok(value) if 0 < value && value <= 100 => config.recall_majority_percentage = value,
+                        _ => return Err(Error::InvalidGovernanceParameters),
+                    },
+                    "election_seats" => match part_data[1].parse::<u16>() {
+                        Ok(value) if 0 < value => config.election_seats = value,
+                        _ => return Err(Error::InvalidGovernanceParameters),
+                    },
+                    "nomination_period" => match part_data[1].parse::<u64>() {
+                        Ok(value) if 0 < value => config.nomination_period = value,
+                        _ => return Err(Error::InvalidGovernanceParameters),
+                    },
+                    "max_execution_attempts" => match part_data[1].parse::<u16>() {
+                        Ok(value) if 0 < value => config.max_execution_attempts = value,
+                        _ => return Err(Error::InvalidGovernanceParameters),
+                    },
+                    "execution_retry_interval" => match part_data[1].parse::<u64>() {
+                        Ok(value) => config.execution_retry_interval = value,
+                        _ => return Err(Error::InvalidGovernanceParameters),
+                    },
+                    "emergency_proposal_types" => {
+                        let mut proposal_types: Vec<ProposalType> = Vec::new();
+                        for type_name in part_data[1].split('/') {
+                            if type_name.len() == 0 {
+                                continue;
+                            }
+                            match self.convert_string_to_proposal_type(type_name) {
+                                Some(value) => proposal_types.push(value),
+                                None => return Err(Error::InvalidGovernanceParameters),
+                            }
+                        }
+                        config.emergency_proposal_types = proposal_types;
+                    }
+                    _ => return Err(Error::InvalidGovernanceParameters),
+                }
+            }
+            if config.voting_mode == VotingMode::TokenWeighted && config.governance_token == None {
+                return Err(Error::GovernanceTokenIsNotSet);
+            }
+            // the emergency proposal and the recall can not be easier to pass than the normal proposal.
+            if config.emergency_voting_period >= config.voting_period
+                || config.emergency_majority_percentage < config.majority_percentage
+                || config.recall_majority_percentage < config.majority_percentage
+            {
+                return Err(Error::InvalidGovernanceParameters);
+            }
+            Ok(config)
+        }
+
+        /// check csv data of the proposal type by the same decoding as the execution.
+        /// * The options and the actions are checked by check_proposal_options and check_proposal_actions.
+        fn check_csv_data(&self, _dao_address: AccountId, _proposal_type: &ProposalType, _csv_data: &String) -> Result<()> {
+            let csv_data = _csv_data.clone();
+            let member_manager_result = match _proposal_type {
+                ProposalType::AddMember => self.member_manager.validate_add_member_csv(csv_data),
+                ProposalType::DeleteMember => self.member_manager.validate_delete_member_csv(csv_data),
+                ProposalType::ChangeElectoralCommissioner => {
+                    self.member_manager.validate_change_electoral_commissioner_csv(_dao_address, csv_data)
+                }
+                ProposalType::RecallElectoralCommissioner => {
+                    self.member_manager.validate_recall_electoral_commissioner_csv(_dao_address, csv_data)
+                }
+                _ => Ok(()),
+            };
+            match member_manager_result {
+                Ok(()) => (),
+                Err(e) => return Err(Error::InvalidMemberManagerCsvData(e)),
+            };
+
+            let csv_data = _csv_data.clone();
+            let dao_manager_result = match _proposal_type {
+                ProposalType::IssueToken => self.dao_manager.validate_add_dao_token_csv(csv_data),
+                ProposalType::ChangeStatusOfTokenSale => self.dao_manager.validate_change_token_sales_status_csv(csv_data),
+                ProposalType::WithdrawTokenSales => self.dao_manager.validate_withdraw_token_proceeds_csv(csv_data),
+                ProposalType::DistributeGovernanceToken => self.dao_manager.validate_distribute_governance_token_csv(csv_data),
+                ProposalType::UseDaoTresury => self.dao_manager.validate_distribute_dao_treasury_csv(csv_data),
+                ProposalType::ExternalCall => self.dao_manager.validate_execute_external_call_csv(csv_data),
+                _ => Ok(()),
+            };
+            match dao_manager_result {
+                Ok(()) => (),
+                Err(e) => return Err(Error::InvalidDaoManagerCsvData(e)),
+            };
+
+            match _proposal_type {
+                ProposalType::ChangeGovernanceParameters => {
+                    match self.decode_governance_config(_dao_address, _csv_data.clone()) {
+                        Ok(_value) => Ok(()),
+                        Err(e) => Err(e),
+                    }
+                }
+                _ => Ok(()),
+            }
+        }
+
+        /// add the proposal for local function and return the proposal id.
+        fn inline_add_proposal(
+            &mut self,
+            proposal_type: ProposalType,
+            dao_address: AccountId,
+            title: String,
+            outline: String,
+            details: String,
+            github_url: String,
+            csv_data: String,
+            is_emergency: bool,
+        ) -> Result<u128> {
+            let caller = self.env().caller();
+            if self
+                .member_manager
+                .modifier_only_member(caller, dao_address)
+                == false
+            {
+                ink_env::debug_println!("################### not member error.");
+                return Err(Error::OnlyMemberDoes);
+            }
+
+            let limit = self.is_tenure_of_electoral_commissioner_expired(dao_address);
+            match proposal_type {
+                ProposalType::ChangeElectoralCommissioner => match limit {
+                    true => (),
+                    false => return Err(Error::NotExpirationOfTermOfElectionCommissioner),
+                },
+                // the recall is allowed at any time.
+                ProposalType::RecallElectoralCommissioner => (),
+                _ => match limit {
+                    true => return Err(Error::ExpirationOfTermOfElectionCommissioner),
+                    false => (),
+                },
+            };
+
+            match self.check_csv_data(dao_address, &proposal_type, &csv_data) {
+                Ok(()) => (),
+                Err(e) => return Err(e),
+            }
+
+            let deposit = self.env().transferred_value();
+            if deposit != self.get_governance_config(dao_address).proposal_deposit {
+                return Err(Error::InvalidDeposit);
+            }
+
+            let mut next_proposal_id = match self.next_proposal_ids.get(&dao_address) {
+                Some(value) => value,
+                None => 0,
+            };
+            if deposit > 0 {
+                self.deposits.insert(&(dao_address, next_proposal_id), &deposit);
+            }
+
+            let config = self.get_governance_config(dao_address);
+            let voting_start_time = self.env().block_timestamp();
+            let voting_period = match is_emergency {
+                true => config.emergency_voting_period,
+                false => config.voting_period,
+            };
+            let voting_end_time = voting_start_time + voting_period;
+            let is_secret_ballot = self.is_secret_ballot_type(&proposal_type);
+            // the reveal period of the emergency proposal is as short as its voting period.
+            let reveal_end_time = match (is_secret_ballot, is_emergency) {
+                (true, true) => voting_end_time + config.emergency_voting_period,
+                (true, false) => voting_end_time + config.reveal_period,
+                (false, _) => voting_end_time,
+            };
+            let proposal_info = ProposalInfo {
+                proposal_type: proposal_type,
+                proposal_id: next_proposal_id,
+                title: title,
+                outline: outline,
+                details: details,
+                status: self::ProposalStatus::Proposed,
+                proposer: caller,
+                github_url: github_url,
+                csv_data: csv_data,
+                revision: 0,
+                is_emergency: is_emergency,
+                is_optimistic: false,
+                voting_start_time: voting_start_time,
+                voting_end_time: voting_end_time,
+                is_secret_ballot: is_secret_ballot,
+                reveal_end_time: reveal_end_time,
+                snapshot_block: self.get_snapshot_block(),
+                membership_epoch: 0,
+                electorate_size: 0,
+                voting_mode: config.voting_mode.clone(),
+                governance_token: config.governance_token,
+                executable_time: 0,
+                execution_deadline: 0,
+            };
+            self.proposal_infoes
+                .insert(&(dao_address, next_proposal_id), &proposal_info);
+            self.inline_add_revision(dao_address, proposal_info.clone());
+            next_proposal_id = next_proposal_id + 1;
+            self.next_proposal_ids.insert(&dao_address, &next_proposal_id);
+            Ok(proposal_info.proposal_id)
+        }
+
+        /// get the filtered proposals for the paginated queries.
+        fn inline_get_filtered_proposals(
+            &self,
+            _dao_address: AccountId,
+            _filter: ProposalFilter,
+            _limit: u32,
+        ) -> (Vec<ProposalInfo>, Option<u128>) {
+            let next_proposal_id = match self.next_proposal_ids.get(&_dao_address) {
+                Some(value) => value,
+                None => 0,
+            };
+            let to_id = match _filter.to_id {
+                Some(value) if value < next_proposal_id => value,
+                _ => next_proposal_id,
+            };
+            scan_page(_filter.from_id, to_id, _limit, |i| {
+                let proposal_info = match self.proposal_infoes.get(&(_dao_address, i)) {
+                    Some(value) => value,
+                    None => return None,
+                };
+                match &_filter.status {
+                    Some(value) if *value != proposal_info.status => return None,
+                    _ => (),
+                }
+                match &_filter.proposal_type {
+                    Some(value) if *value != proposal_info.proposal_type => return None,
+                    _ => (),
+                }
+                match _filter.proposer {
+                    Some(value) if value != proposal_info.proposer => return None,
+                    _ => (),
+                }
+                Some(proposal_info)
+            })
+        }
+
+        /// check options of the ranked choice proposal.
+        fn check_proposal_options(&self, dao_address: AccountId, options: &Vec<ProposalOption>) -> Result<()> {
+            if options.len() < 2 || options.len() > MAX_PROPOSAL_OPTIONS {
+                return Err(Error::InvalidProposalOptions);
+            }
+            for option in options.iter() {
+                match option.proposal_type {
+                    ProposalType::ChangeElectoralCommissioner
+                    | ProposalType::RecallElectoralCommissioner
+                    | ProposalType::RankedChoice
+                    | ProposalType::Composite => return Err(Error::InvalidProposalOptions),
+                    _ => (),
+                }
+                // the secret ballot type can not be voted in the open ballot.
+                if self.is_secret_ballot_type(&option.proposal_type) {
+                    return Err(Error::InvalidProposalOptions);
+                }
+                match self.check_csv_data(dao_address, &option.proposal_type, &option.csv_data) {
+                    Ok(()) => (),
+                    Err(e) => return Err(e),
+                }
+            }
+            Ok(())
+        }
+
+        /// check actions of the composite proposal.
+        fn check_proposal_actions(&self, dao_address: AccountId, actions: &Vec<ProposalAction>) -> Result<()> {
+            if actions.len() == 0 || actions.len() > MAX_PROPOSAL_ACTIONS {
+                return Err(Error::InvalidProposalActions);
+            }
+            for action in actions.iter() {
+                match action.proposal_type {
+                    ProposalType::ChangeElectoralCommissioner
+                    | ProposalType::RecallElectoralCommissioner
+                    | ProposalType::RankedChoice
+                    | ProposalType::Composite => return Err(Error::InvalidProposalActions),
+                    _ => (),
+                }
+                // the secret ballot type can not be voted in the open ballot.
+                if self.is_secret_ballot_type(&action.proposal_type) {
+                    return Err(Error::InvalidProposalActions);
+                }
+                match self.check_csv_data(dao_address, &action.proposal_type, &action.csv_data) {
                     Ok(()) => (),
                     Err(e) => return Err(e),
-                };
+                }
             }
             Ok(())
         }
 
-        /// execute the proposal
-        #[ink(message)]
-        pub fn execute_proposal(
-            &mut self,
-            _dao_address: AccountId,
-            _proposal_id: u128,
-        ) -> Result<()> {
-            let caller = self.env().caller();
-            if self
-                .member_manager
-                .modifier_only_member(caller, _dao_address)
-                == false
-            {
-                ink_env::debug_println!("########################### OnlyMemberDoes Error.");
-                return Err(Error::OnlyMemberDoes);
+        /// return the transferred value to the caller if adding the proposal is failure.
+        /// * Returning an error does not revert the transfer to this contract.
+        fn inline_return_transferred_value_on_error(&mut self, result: Result<u128>) -> Result<u128> {
+            let transferred_value = self.env().transferred_value();
+            if result.is_err() && transferred_value > 0 {
+                match self.env().transfer(self.env().caller(), transferred_value) {
+                    Ok(()) => (),
+                    Err(_e) => ink_env::debug_println!("########################### Returning Transferred Value Error."),
+                }
             }
+            result
+        }
 
-            let mut proposal_info: ProposalInfo =
-                match self.proposal_infoes.get(&(_dao_address, _proposal_id)) {
-                    Some(value) => {
-                        if value.status != ProposalStatus::Running {
-                            ink_env::debug_println!("########################### NotRunning Error.");
-                            return Err(Error::NotRunning);
-                        }
-                        value
-                    }
-                    None => {
-                        ink_env::debug_println!("########################### ProposalDoesNotExist Error.");
-                        return Err(Error::ProposalDoesNotExist);
-                    },
-                };
+        /// return the deposit to the proposer, or forfeit it to the dao treasury.
+        fn inline_settle_deposit(&mut self, _dao_address: AccountId, _proposal_info: ProposalInfo, _is_forfeited: bool) {
+            let deposit = match self.deposits.get(&(_dao_address, _proposal_info.proposal_id)) {
+                Some(value) => value,
+                None => return,
+            };
+            let to = match _is_forfeited {
+                true => _dao_address,
+                false => _proposal_info.proposer,
+            };
+            match self.env().transfer(to, deposit) {
+                Ok(()) => self.deposits.remove(&(_dao_address, _proposal_info.proposal_id)),
+                Err(_e) => ink_env::debug_println!("########################### Settling Deposit Error."),
+            }
+        }
 
-            match proposal_info.proposal_type {
-                ProposalType::AddMember => {
-                    match self.member_manager.add_member(_dao_address, proposal_info.clone().csv_data) {
-                        Ok(()) => (),
-                        Err(_e) => {
-                            ink_env::debug_println!("########################### Execute Error.");
-                            return Err(Error::InvalidMemberManagerCall)
-                        },
-                    }
-                },
-                ProposalType::DeleteMember => {
-                    match self.member_manager.delete_member(_dao_address, proposal_info.clone().csv_data){
-                        Ok(()) => (),
-                        Err(_e) => {
-                            ink_env::debug_println!("########################### Execute Error.");
-                            return Err(Error::InvalidMemberManagerCall)
-                        },
-                    }
-                },
-                ProposalType::ChangeElectoralCommissioner => {
-                    match self.member_manager.change_electoral_commissioner(_dao_address,proposal_info.clone().csv_data,){
-                        Ok(()) => (),
-                        Err(_e) => return Err(Error::InvalidMemberManagerCall),
-                    };
-                    self.clear_tenure_count(_dao_address);
-                },
-                ProposalType::IssueToken => {
-                    match self.dao_manager.add_dao_token(_dao_address,proposal_info.clone().csv_data){
-                        Ok(()) => (),
-                        Err(_e) => return Err(Error::InvalidDaoManagerCall),
-                    }
-                },
-                ProposalType::ChangeStatusOfTokenSale => {
-                    match self.dao_manager.change_token_sales_status(_dao_address,proposal_info.clone().csv_data){
-                        Ok(()) => (),
-                        Err(_e) => return Err(Error::InvalidDaoManagerCall),
+        /// get the candidates of the election.
+        #[inline]
+        fn get_candidate_list(&self, _dao_address: AccountId, _election_id: u128) -> Vec<AccountId> {
+            match self.candidates.get(&(_dao_address, _election_id)) {
+                Some(value) => value,
+                None => Vec::new(),
+            }
+        }
+
+        /// get the candidates who have the most votes up to seats.
+        /// * A candidate who is nominated earlier wins a tie.
+        /// * A candidate who is no longer a member is skipped.
+        fn get_election_winners(&self, _dao_address: AccountId, _election_info: ElectionInfo) -> Vec<AccountId> {
+            let mut candidate_info_list: Vec<CandidateInfo> = self
+                .get_candidates(_dao_address, _election_info.election_id)
+                .into_iter()
+                .filter(|value| {
+                    value.votes > 0 && self.member_manager.modifier_only_member(value.candidate, _dao_address)
+                })
+                .collect();
+            // the stable sort keeps the order of nomination in a tie.
+            candidate_info_list.sort_by(|a, b| b.votes.cmp(&a.votes));
+            candidate_info_list
+                .into_iter()
+                .take(_election_info.seats as usize)
+                .map(|value| value.candidate)
+                .collect()
+        }
+
+        /// move the proposal to Voting and record the snapshot of the electorate.
+        /// * The snapshot block, the membership epoch, the count of members, the voting mode and
+        ///   the governance token are recorded.
+        fn inline_open_voting(&mut self, _dao_address: AccountId, mut _proposal_info: ProposalInfo) -> ProposalInfo {
+            let config = self.get_governance_config(_dao_address);
+            _proposal_info.status = ProposalStatus::Voting;
+            _proposal_info.voting_mode = config.voting_mode;
+            _proposal_info.governance_token = config.governance_token;
+            _proposal_info.snapshot_block = self.get_snapshot_block();
+            _proposal_info.membership_epoch = self.member_manager.get_membership_epoch(_dao_address);
+            _proposal_info.electorate_size = self.member_manager.get_member_list(_dao_address).len() as u32;
+            self.inline_change_proposal_status(_dao_address, _proposal_info.clone());
+            _proposal_info
+        }
+
+        /// get the snapshot block which is recorded when voting opens.
+        /// * The previous block is used so that transfers in the current block do not change the snapshot.
+        #[inline]
+        fn get_snapshot_block(&self) -> BlockNumber {
+            self.env().block_number().saturating_sub(1)
+        }
+
+        /// get the weight of the vote.
+        /// * OnePersonOneVote: 1
+        /// * TokenWeighted: the balance of the governance token at the snapshot block
+        fn get_vote_weight(
+            &self,
+            _voter: AccountId,
+            _snapshot_block: BlockNumber,
+            _voting_mode: &VotingMode,
+            _governance_token: Option<AccountId>,
+        ) -> Result<Balance> {
+            let weight = match _voting_mode {
+                VotingMode::OnePersonOneVote => 1,
+                VotingMode::TokenWeighted => match _governance_token {
+                    Some(token_address) => {
+                        let instance: DaoGovernanceTokenRef =
+                            ink_env::call::FromAccountId::from_account_id(token_address);
+                        instance.balance_of_at(_voter, _snapshot_block)
                     }
+                    None => return Err(Error::GovernanceTokenIsNotSet),
                 },
-                ProposalType::WithdrawTokenSales => {
-                    match self.dao_manager.withdraw_token_proceeds(_dao_address,proposal_info.clone().csv_data){
-                        Ok(()) => (),
-                        Err(_e) => return Err(Error::InvalidDaoManagerCall),
+            };
+            if weight == 0 {
+                return Err(Error::NoVotingPower);
+            }
+            Ok(weight)
+        }
+
+        /// get the total weight of the votes that can be cast.
+        /// * OnePersonOneVote: the count of members when the voting opened
+        /// * TokenWeighted: the circulating supply of the governance token at the snapshot block
+        fn get_total_vote_weight(
+            &self,
+            _snapshot_block: BlockNumber,
+            _electorate_size: u32,
+            _voting_mode: &VotingMode,
+            _governance_token: Option<AccountId>,
+        ) -> Balance {
+            match _voting_mode {
+                VotingMode::OnePersonOneVote => _electorate_size as Balance,
+                VotingMode::TokenWeighted => match _governance_token {
+                    Some(token_address) => {
+                        let instance: DaoGovernanceTokenRef =
+                            ink_env::call::FromAccountId::from_account_id(token_address);
+                        instance.circulating_supply_at(_snapshot_block)
                     }
+                    None => 0,
                 },
-                ProposalType::DistributeGovernanceToken => {
-                    match self.dao_manager.distribute_governance_token(_dao_address,proposal_info.clone().csv_data){
-                        Ok(()) => (),
-                        Err(_e) => return Err(Error::InvalidDaoManagerCall),
+            }
+        }
+
+        /// revoke delegation for local function.
+        #[inline]
+        fn inline_revoke_delegation(&mut self, _dao_address: AccountId, _delegator: AccountId) {
+            let delegate = match self.delegations.get(&(_dao_address, _delegator)) {
+                Some(value) => value,
+                None => return,
+            };
+            let mut delegator_list = self.get_delegators(_dao_address, delegate);
+            delegator_list.retain(|value| *value != _delegator);
+            self.delegators.insert(&(_dao_address, delegate), &delegator_list);
+            self.delegations.remove(&(_dao_address, _delegator));
+        }
+
+        /// record the ballot of the voter.
+        /// * The voter is indexed only when the voter votes for the first time.
+        /// * The delegators of the voter who have not voted are frozen into the ballot during the voting period,
+        ///   so the delegations which are changed later are not counted.
+        /// * When a delegator votes directly, the weight is taken back from the delegate's ballot.
+        #[inline]
+        fn inline_record_ballot(
+            &mut self,
+            _dao_address: AccountId,
+            _proposal_info: ProposalInfo,
+            _voter: AccountId,
+            _choice: BallotChoice,
+            _weight: Balance,
+        ) {
+            let proposal_id = _proposal_info.proposal_id;
+            let (mut delegators, mut delegated_weight) =
+                match self.ballots.get(&(_dao_address, proposal_id, _voter)) {
+                    Some(value) => (value.delegators, value.delegated_weight),
+                    None => {
+                        let ballot_count = self.get_ballot_count(_dao_address, proposal_id);
+                        self.ballot_voters
+                            .insert(&(_dao_address, proposal_id, ballot_count), &_voter);
+                        self.ballot_counts
+                            .insert(&(_dao_address, proposal_id), &(ballot_count + 1));
+                        self.inline_take_back_delegated_weight(_dao_address, proposal_id, _voter, _weight);
+                        (Vec::new(), 0)
                     }
-                },
-                ProposalType::UseDaoTresury => {
-                    match self.dao_manager.distribute_dao_treasury(_dao_address,proposal_info.clone().csv_data){
-                        Ok(()) => (),
-                        Err(_e) => return Err(Error::InvalidDaoManagerCall),
+                };
+            if self.is_in_voting_period(_proposal_info.clone()) {
+                for delegator in self.get_delegators(_dao_address, _voter) {
+                    if self.has_voted(_dao_address, proposal_id, delegator)
+                        || self.delegated_ballots.get(&(_dao_address, proposal_id, delegator)) != None
+                        || self
+                            .member_manager
+                            .modifier_only_member_at_epoch(delegator, _dao_address, _proposal_info.membership_epoch)
+                            == false
+                    {
+                        continue;
                     }
-                },
+                    match self.get_vote_weight(
+                        delegator,
+                        _proposal_info.snapshot_block,
+                        &_proposal_info.voting_mode,
+                        _proposal_info.governance_token,
+                    ) {
+                        Ok(value) => {
+                            delegators.push(delegator);
+                            delegated_weight = delegated_weight + value;
+                            self.delegated_ballots
+                                .insert(&(_dao_address, proposal_id, delegator), &_voter);
+                        }
+                        Err(_e) => continue,
+                    };
+                }
+            }
+            let ballot = Ballot {
+                choice: _choice,
+                weight: _weight,
+                delegators: delegators,
+                delegated_weight: delegated_weight,
+                timestamp: self.env().block_timestamp(),
             };
-            proposal_info.status = ProposalStatus::Finished;
-            self.inline_change_proposal_status(_dao_address, proposal_info.clone());
-            Ok(())
+            self.ballots.insert(&(_dao_address, proposal_id, _voter), &ballot);
         }
 
-        /// check tenure count
-        #[ink(message)]
-        pub fn is_limit_tenure_count_of_electoral_commissioner(&self, _dao_address: AccountId) -> bool {
-            match self.count_of_tenure.get(&_dao_address) {
-                Some(value) => {
-                    ink_env::debug_println!("########################### count_of_tenure : {:?}", value);
-                    ink_env::debug_println!("########################### TENURE_OF_LIMIT : {:?}", TENURE_OF_LIMIT);
-                    return value >= TENURE_OF_LIMIT;
-                },
-                None => return false,
+        /// take the weight of the delegator who votes directly back from the delegate's ballot.
+        #[inline]
+        fn inline_take_back_delegated_weight(
+            &mut self,
+            _dao_address: AccountId,
+            _proposal_id: u128,
+            _delegator: AccountId,
+            _weight: Balance,
+        ) {
+            let delegate = match self.delegated_ballots.get(&(_dao_address, _proposal_id, _delegator)) {
+                Some(value) => value,
+                None => return,
             };
+            match self.ballots.get(&(_dao_address, _proposal_id, delegate)) {
+                Some(mut ballot) => {
+                    ballot.delegators.retain(|value| *value != _delegator);
+                    ballot.delegated_weight = ballot.delegated_weight.saturating_sub(_weight);
+                    self.ballots.insert(&(_dao_address, _proposal_id, delegate), &ballot);
+                }
+                None => (),
+            }
+            self.delegated_ballots.remove(&(_dao_address, _proposal_id, _delegator));
         }
 
-        /// add tenure count
+        /// check whether the voter has voted for the proposal.
         #[inline]
-        fn add_tenure_count(&mut self, _dao_address: AccountId) {
-            match self.count_of_tenure.get(&_dao_address) {
-                Some(value) => {
-                    let count = value + 1;
-                    self.count_of_tenure.insert(&_dao_address, &count);
+        fn has_voted(&self, _dao_address: AccountId, _proposal_id: u128, _voter: AccountId) -> bool {
+            match self.ballots.get(&(_dao_address, _proposal_id, _voter)) {
+                Some(_value) => true,
+                None => false,
+            }
+        }
+
+        /// get the voters of the proposal.
+        fn get_voter_list(&self, _dao_address: AccountId, _proposal_id: u128) -> Vec<AccountId> {
+            let mut voter_list: Vec<AccountId> = Vec::new();
+            for i in 0..self.get_ballot_count(_dao_address, _proposal_id) {
+                match self.ballot_voters.get(&(_dao_address, _proposal_id, i)) {
+                    Some(value) => voter_list.push(value),
+                    None => continue,
                 }
-                None => {
-                    let count = 1;
-                    self.count_of_tenure.insert(&_dao_address, &count);
-                },
             }
+            voter_list
+        }
+
+        /// add the votes of delegators who did not vote directly to their delegate's choice.
+        fn add_delegated_votes(
+            &self,
+            _dao_address: AccountId,
+            _proposal_id: u128,
+            mut _voted_result: VotingResult,
+        ) -> VotingResult {
+            for voter in self.get_voter_list(_dao_address, _proposal_id) {
+                let (vote, weight) = match self.ballots.get(&(_dao_address, _proposal_id, voter)) {
+                    Some(Ballot {
+                        choice: BallotChoice::Vote(value),
+                        delegated_weight,
+                        ..
+                    }) => (value, delegated_weight),
+                    _ => continue,
+                };
+                _voted_result = self.inline_add_vote(_voted_result, vote, weight);
+                _voted_result.delegated = _voted_result.delegated + weight;
+            }
+            _voted_result
+        }
+
+        /// add the vote to the voting result.
+        #[inline]
+        fn inline_add_vote(&self, mut _voted_result: VotingResult, _vote: VoteType, _weight: Balance) -> VotingResult {
+            match _vote {
+                VoteType::Yes => _voted_result.yes = _voted_result.yes + _weight,
+                VoteType::No => _voted_result.no = _voted_result.no + _weight,
+                VoteType::Abstain => _voted_result.abstain = _voted_result.abstain + _weight,
+            }
+            _voted_result
+        }
+
+        /// remove the vote from the voting result.
+        #[inline]
+        fn inline_remove_vote(&self, mut _voted_result: VotingResult, _vote: VoteType, _weight: Balance) -> VotingResult {
+            match _vote {
+                VoteType::Yes => _voted_result.yes = _voted_result.yes - _weight,
+                VoteType::No => _voted_result.no = _voted_result.no - _weight,
+                VoteType::Abstain => _voted_result.abstain = _voted_result.abstain - _weight,
+            }
+            _voted_result
         }
 
-        /// clear tenure count
-        fn clear_tenure_count(&mut self, _dao_address: AccountId){
-            match self.count_of_tenure.get(&_dao_address) {
-                Some(_value) => {
-                    let count = 0;
-                    self.count_of_tenure.insert(&_dao_address, &count);
+        /// count ranked ballots by instant-runoff.
+        /// * The option which has the majority of the first preferences of the continuing ballots wins.
+        /// * Otherwise the option which has the fewest votes is eliminated and the ballots are counted again.
+        fn count_ranked_choice_votes(
+            &mut self,
+            _dao_address: AccountId,
+            _proposal_info: ProposalInfo,
+            _total_weight: Balance,
+        ) -> Result<ProposalStatus> {
+            let proposal_id = _proposal_info.proposal_id;
+            let option_count = self.get_proposal_options(_dao_address, proposal_id).len();
+            let mut ballots: Vec<(Vec<u8>, Balance)> = Vec::new();
+            let mut voted_weight = 0;
+            for voter in self.get_voter_list(_dao_address, proposal_id) {
+                let (ranking, weight) = match self.ballots.get(&(_dao_address, proposal_id, voter)) {
+                    Some(Ballot {
+                        choice: BallotChoice::Ranking(value),
+                        weight,
+                        delegated_weight,
+                        ..
+                    }) => (value, weight + delegated_weight),
+                    _ => continue,
+                };
+                voted_weight = voted_weight + weight;
+                ballots.push((ranking, weight));
+            }
+
+            let config = self.get_governance_config(_dao_address);
+            if _total_weight == 0
+                || (voted_weight * 100 / _total_weight) < config.required_voter_turnout_percentage as Balance
+            {
+                return Err(Error::VoterTurnoutNotAchieved);
+            }
+
+            match tally_instant_runoff(&ballots, option_count) {
+                Some(value) => {
+                    self.winning_options.insert(&(_dao_address, proposal_id), &value);
+                    Ok(ProposalStatus::Running)
                 }
-                None => (),
+                None => Ok(ProposalStatus::Denied),
             }
         }
 
         /// count voting result.
+        /// * The votes of delegators which are frozen in the ballots are added to the result at this time.
         #[inline]
         fn count_votes_of_proposal(
             &mut self,
             _dao_address: AccountId,
             _proposal_id: u128,
         ) -> Result<()> {
-            let member_count: u16 = self
-                .member_manager
-                .get_member_list(_dao_address)
-                .len()
-                .try_into()
-                .unwrap();
             let mut proposal_info: ProposalInfo =
                 match self.proposal_infoes.get(&(_dao_address, _proposal_id)) {
                     Some(value) => value,
                     None => return Err(Error::ProposalDoesNotExist),
                 };
-            let voted_result: VotingResult =
+            if proposal_info.proposal_type == ProposalType::RankedChoice {
+                let total_weight = self.get_total_vote_weight(
+                    proposal_info.snapshot_block,
+                    proposal_info.electorate_size,
+                    &proposal_info.voting_mode,
+                    proposal_info.governance_token,
+                );
+                let is_forfeited =
+                    match self.count_ranked_choice_votes(_dao_address, proposal_info.clone(), total_weight) {
+                        Ok(value) => {
+                            proposal_info.status = value;
+                            false
+                        }
+                        Err(_e) => {
+                            proposal_info.status = ProposalStatus::Denied;
+                            true
+                        }
+                    };
+                proposal_info = self.inline_set_execution_schedule(_dao_address, proposal_info);
+                self.inline_change_proposal_status(_dao_address, proposal_info.clone());
+                self.inline_settle_deposit(_dao_address, proposal_info, is_forfeited);
+                return Ok(());
+            }
+            let total_weight = self.get_total_vote_weight(
+                proposal_info.snapshot_block,
+                proposal_info.electorate_size,
+                &proposal_info.voting_mode,
+                proposal_info.governance_token,
+            );
+            let mut voted_result: VotingResult =
                 match self.voting_results.get(&(_dao_address, _proposal_id)) {
                     Some(value) => value,
                     None => VotingResult {
                         proposal_id: _proposal_id,
                         yes: 0,
                         no: 0,
+                        abstain: 0,
+                        delegated: 0,
                     },
                 };
+            voted_result = self.add_delegated_votes(_dao_address, _proposal_id, voted_result);
+            self.voting_results
+                .insert(&(_dao_address, _proposal_id), &voted_result);
 
-            self.add_tenure_count(_dao_address);
-
-            let voter_count = voted_result.yes + voted_result.no;
-            if (voter_count / member_count * 100) < REQUIRED_VOTER_TURNOUT_PERCENTAGE_DEFINITION {
+            let config = self.get_governance_config(_dao_address);
+            let voted_weight = voted_result.yes + voted_result.no + voted_result.abstain;
+            if total_weight == 0
+                || (voted_weight * 100 / total_weight) < config.required_voter_turnout_percentage as Balance
+            {
                 proposal_info.status = ProposalStatus::Denied;
-                self.inline_change_proposal_status(_dao_address, proposal_info);
+                self.inline_change_proposal_status(_dao_address, proposal_info.clone());
+                // the deposit is forfeited when the required voter turnout is not achieved.
+                self.inline_settle_deposit(_dao_address, proposal_info, true);
                 return Ok(());
             }
-            match (voted_result.yes / member_count * 100) >= MAJORITY_PERCENTAGE_DEFINITION {
+            // abstain votes are not counted toward the majority.
+            let decided_weight = voted_result.yes + voted_result.no;
+            // the recall and the emergency proposal require the supermajority.
+            let majority_percentage = match (proposal_info.proposal_type.clone(), proposal_info.is_emergency) {
+                (ProposalType::RecallElectoralCommissioner, true) => {
+                    match config.recall_majority_percentage > config.emergency_majority_percentage {
+                        true => config.recall_majority_percentage,
+                        false => config.emergency_majority_percentage,
+                    }
+                }
+                (ProposalType::RecallElectoralCommissioner, false) => config.recall_majority_percentage,
+                (_, true) => config.emergency_majority_percentage,
+                (_, false) => config.majority_percentage,
+            };
+            match decided_weight != 0
+                && (voted_result.yes * 100 / decided_weight) >= majority_percentage as Balance
+            {
                 true => proposal_info.status = ProposalStatus::Running,
                 false => proposal_info.status = ProposalStatus::Denied,
             }
-            self.inline_change_proposal_status(_dao_address, proposal_info);
+            proposal_info = self.inline_set_execution_schedule(_dao_address, proposal_info);
+            self.inline_change_proposal_status(_dao_address, proposal_info.clone());
+            self.inline_settle_deposit(_dao_address, proposal_info, false);
 
             Ok(())
         }
 
+        /// set the executable time and the execution deadline if the proposal passed.
+        #[inline]
+        fn inline_set_execution_schedule(
+            &self,
+            _dao_address: AccountId,
+            mut _proposal_info: ProposalInfo,
+        ) -> ProposalInfo {
+            if _proposal_info.status == ProposalStatus::Running {
+                let config = self.get_governance_config(_dao_address);
+                _proposal_info.executable_time = self.env().block_timestamp() + config.timelock_period;
+                _proposal_info.execution_deadline = _proposal_info.executable_time + config.execution_period;
+            }
+            _proposal_info
+        }
+
+        /// add the current revision of the proposal to the revision history.
+        #[inline]
+        fn inline_add_revision(&mut self, _dao_address: AccountId, _proposal_info: ProposalInfo) {
+            let revision = ProposalRevision {
+                revision: _proposal_info.revision,
+                title: _proposal_info.title,
+                outline: _proposal_info.outline,
+                details: _proposal_info.details,
+                github_url: _proposal_info.github_url,
+                csv_data: _proposal_info.csv_data,
+                timestamp: self.env().block_timestamp(),
+            };
+            self.proposal_revisions
+                .insert(&(_dao_address, _proposal_info.proposal_id, revision.revision), &revision);
+        }
+
+        /// check whether the votes of the proposal type are committed in secret.
+        #[inline]
+        fn is_secret_ballot_type(&self, _proposal_type: &ProposalType) -> bool {
+            match _proposal_type {
+                ProposalType::DeleteMember
+                | ProposalType::ChangeElectoralCommissioner
+                | ProposalType::RecallElectoralCommissioner => true,
+                _ => false,
+            }
+        }
+
+        /// check whether the current block timestamp is in the voting period.
+        #[inline]
+        fn is_in_voting_period(&self, _proposal_info: ProposalInfo) -> bool {
+            if _proposal_info.is_optimistic {
+                return false;
+            }
+            let now = self.env().block_timestamp();
+            _proposal_info.voting_start_time <= now && now < _proposal_info.voting_end_time
+        }
+
+        /// set the voting period and the reveal period of the proposal from now.
+        /// * The optimistic proposal has the challenge period instead of the voting period.
+        fn inline_set_voting_schedule(&self, _dao_address: AccountId, mut _proposal_info: ProposalInfo) -> ProposalInfo {
+            let config = self.get_governance_config(_dao_address);
+            let now = self.env().block_timestamp();
+            let voting_period = match (_proposal_info.is_optimistic, _proposal_info.is_emergency) {
+                (true, _) => config.challenge_period,
+                (false, true) => config.emergency_voting_period,
+                (false, false) => config.voting_period,
+            };
+            _proposal_info.voting_start_time = now;
+            _proposal_info.voting_end_time = now + voting_period;
+            // the reveal period of the emergency proposal is as short as its voting period.
+            _proposal_info.reveal_end_time =
+                match (_proposal_info.is_optimistic, _proposal_info.is_secret_ballot, _proposal_info.is_emergency) {
+                    (false, true, true) => _proposal_info.voting_end_time + config.emergency_voting_period,
+                    (false, true, false) => _proposal_info.voting_end_time + config.reveal_period,
+                    _ => _proposal_info.voting_end_time,
+                };
+            _proposal_info
+        }
+
+        /// check whether the proposal can be proposed as the optimistic proposal.
+        /// * The csv data is decoded by the dao manager to get the value which leaves the DAO.
+        fn check_optimistic_proposal(
+            &self,
+            _dao_address: AccountId,
+            _proposal_type: &ProposalType,
+            _csv_data: &String,
+        ) -> Result<()> {
+            let config = self.get_governance_config(_dao_address);
+            if !config.optimistic_proposal_types.contains(_proposal_type) {
+                return Err(Error::NotOptimisticProposalType);
+            }
+            // the value which leaves the DAO has to be within the limit.
+            let transferred_value = match _proposal_type {
+                ProposalType::UseDaoTresury => self.dao_manager.get_distribute_dao_treasury_amount(_csv_data.clone()),
+                _ => Ok(0),
+            };
+            match transferred_value {
+                Ok(value) if value <= config.optimistic_treasury_limit => Ok(()),
+                Ok(_value) => Err(Error::OptimisticLimitExceeded),
+                Err(e) => Err(Error::InvalidDaoManagerCsvData(e)),
+            }
+        }
+
+        #[inline]
+        fn convert_string_to_accountid(&self, account_str: &str) -> Option<AccountId> {
+            let mut output = vec![0xFF; 35];
+            // the ss58 address is 35 bytes: prefix ( 1 ) + public key ( 32 ) + checksum ( 2 ).
+            match bs58::decode(account_str).into(&mut output) {
+                Ok(35) => (),
+                _ => return None,
+            };
+            let cut_address_vec: Vec<_> = output.drain(1..33).collect();
+            let mut array = [0; 32];
+            let bytes = &cut_address_vec[..array.len()];
+            array.copy_from_slice(bytes);
+            let account_id: AccountId = array.into();
+            Some(account_id)
+        }
+
+        /// convert the name of the proposal type which can be proposed by add_proposal.
+        #[inline]
+        fn convert_string_to_proposal_type(&self, _type_name: &str) -> Option<ProposalType> {
+            match _type_name {
+                "AddMember" => Some(ProposalType::AddMember),
+                "DeleteMember" => Some(ProposalType::DeleteMember),
+                "ChangeElectoralCommissioner" => Some(ProposalType::ChangeElectoralCommissioner),
+                "UseDaoTresury" => Some(ProposalType::UseDaoTresury),
+                "IssueToken" => Some(ProposalType::IssueToken),
+                "ChangeStatusOfTokenSale" => Some(ProposalType::ChangeStatusOfTokenSale),
+                "WithdrawTokenSales" => Some(ProposalType::WithdrawTokenSales),
+                "DistributeGovernanceToken" => Some(ProposalType::DistributeGovernanceToken),
+                "ChangeGovernanceParameters" => Some(ProposalType::ChangeGovernanceParameters),
+                "ExternalCall" => Some(ProposalType::ExternalCall),
+                "RecallElectoralCommissioner" => Some(ProposalType::RecallElectoralCommissioner),
+                _ => None,
+            }
+        }
+
         /// change status for local function.
         #[inline]
         fn inline_change_proposal_status(
@@ -559,7 +3234,7 @@ pub mod proposal_manager {
         ) -> bool {
             match _proposal_info.status {
                 ProposalStatus::Proposed => match _status {
-                    ProposalStatus::Voting => return true,
+                    ProposalStatus::Voting => return !_proposal_info.is_optimistic,
                     _ => return false,
                 },
                 ProposalStatus::Voting => match _status {
@@ -570,6 +3245,93 @@ pub mod proposal_manager {
             }
         }
     }
+
+    /// tally ranked ballots by instant-runoff and return the index of the winning option.
+    /// * ballots: ( indexes of options in order of preference, weight )
+    /// * The option which has the majority of the first preferences of the continuing ballots wins.
+    /// * Otherwise the option which has the fewest votes is eliminated and the ballots are counted again.
+    /// * In a tie, the earlier option wins and the later option is eliminated.
+    /// * None is returned when no ballot remains.
+    pub(crate) fn tally_instant_runoff(ballots: &Vec<(Vec<u8>, Balance)>, option_count: usize) -> Option<u8> {
+        let mut eliminated = vec![false; option_count];
+        loop {
+            let mut counts: Vec<Balance> = vec![0; option_count];
+            let mut continuing_weight = 0;
+            for (ranking, weight) in ballots.iter() {
+                match ranking.iter().find(|index| !eliminated[**index as usize]) {
+                    Some(index) => {
+                        counts[*index as usize] = counts[*index as usize] + weight;
+                        continuing_weight = continuing_weight + weight;
+                    }
+                    None => continue,
+                }
+            }
+            if continuing_weight == 0 {
+                return None;
+            }
+
+            let mut most: Option<usize> = None;
+            let mut fewest: Option<usize> = None;
+            let mut remaining = 0;
+            for i in 0..option_count {
+                if eliminated[i] {
+                    continue;
+                }
+                remaining = remaining + 1;
+                match most {
+                    Some(value) if counts[value] >= counts[i] => (),
+                    _ => most = Some(i),
+                }
+                match fewest {
+                    Some(value) if counts[value] < counts[i] => (),
+                    _ => fewest = Some(i),
+                }
+            }
+            let (most, fewest) = match (most, fewest) {
+                (Some(most), Some(fewest)) => (most, fewest),
+                _ => return None,
+            };
+            if counts[most] * 2 > continuing_weight || remaining == 1 {
+                return Some(most as u8);
+            }
+            eliminated[fewest] = true;
+        }
+    }
+
+    /// scan ids from from_id to to_id ( exclusive ) and return the found items with the next cursor.
+    /// * At most limit ( from 1 up to MAX_PAGE_SIZE ) items are returned, and at most MAX_SCAN_SIZE ids are scanned.
+    /// * The limit is at least 1 so that the cursor always advances.
+    /// * The next cursor is None when all ids until to_id have been scanned.
+    pub(crate) fn scan_page<T>(
+        from_id: u128,
+        to_id: u128,
+        limit: u32,
+        mut find: impl FnMut(u128) -> Option<T>,
+    ) -> (Vec<T>, Option<u128>) {
+        let mut item_list: Vec<T> = Vec::new();
+        let limit = match limit {
+            0 => 1,
+            value if value > MAX_PAGE_SIZE => MAX_PAGE_SIZE,
+            value => value,
+        };
+        let scan_end_id = match to_id > from_id.saturating_add(MAX_SCAN_SIZE) {
+            true => from_id + MAX_SCAN_SIZE,
+            false => to_id,
+        };
+        for i in from_id..scan_end_id {
+            if item_list.len() >= limit as usize {
+                return (item_list, Some(i));
+            }
+            match find(i) {
+                Some(value) => item_list.push(value),
+                None => continue,
+            }
+        }
+        match scan_end_id < to_id {
+            true => (item_list, Some(scan_end_id)),
+            false => (item_list, None),
+        }
+    }
 }
 
 /// Unit tests in Rust are normally defined within such a `#[cfg(test)]`
@@ -604,6 +3366,96 @@ mod tests {
         // assert_eq!(Ok(()), res);
     }
 
+    /// The option which has the majority of the first preferences wins without elimination.
+    #[ink::test]
+    fn tally_instant_runoff_majority_works() {
+        let ballots: Vec<(Vec<u8>, u128)> = vec![
+            (vec![0, 1, 2], 3),
+            (vec![1, 0, 2], 1),
+            (vec![2, 1, 0], 1),
+        ];
+        assert_eq!(proposal_manager::tally_instant_runoff(&ballots, 3), Some(0));
+    }
+
+    /// The option which has the fewest votes is eliminated and its ballots move to the next preference.
+    #[ink::test]
+    fn tally_instant_runoff_elimination_works() {
+        let ballots: Vec<(Vec<u8>, u128)> = vec![
+            (vec![0, 2], 4),
+            (vec![1, 2], 3),
+            (vec![2, 1], 2),
+        ];
+        // 2 is eliminated first, and its ballots move to 1. ( 0: 4, 1: 5 )
+        assert_eq!(proposal_manager::tally_instant_runoff(&ballots, 3), Some(1));
+    }
+
+    /// In a tie, the later option is eliminated and the earlier option wins.
+    #[ink::test]
+    fn tally_instant_runoff_tie_works() {
+        let ballots: Vec<(Vec<u8>, u128)> = vec![(vec![0], 2), (vec![1], 2), (vec![2], 1), (vec![2], 1)];
+        // 0, 1 and 2 are tied at 2, so 2 is eliminated, and then 0 wins the tie with 1.
+        assert_eq!(proposal_manager::tally_instant_runoff(&ballots, 3), Some(0));
+        let ballots: Vec<(Vec<u8>, u128)> = vec![(vec![1], 1), (vec![0], 1)];
+        assert_eq!(proposal_manager::tally_instant_runoff(&ballots, 2), Some(0));
+    }
+
+    /// The cursor points to the next id after a full page, and None after the last id.
+    #[ink::test]
+    fn scan_page_works() {
+        let (items, cursor) = proposal_manager::scan_page(0, 10, 3, |i| Some(i));
+        assert_eq!(items, vec![0, 1, 2]);
+        assert_eq!(cursor, Some(3));
+        let (items, cursor) = proposal_manager::scan_page(9, 10, 3, |i| Some(i));
+        assert_eq!(items, vec![9]);
+        assert_eq!(cursor, None);
+        let (items, cursor) = proposal_manager::scan_page(0, 5, 5, |i| Some(i));
+        assert_eq!(items, vec![0, 1, 2, 3, 4]);
+        assert_eq!(cursor, None);
+    }
+
+    /// The limit of 0 is treated as 1 so that paging until None terminates.
+    #[ink::test]
+    fn scan_page_zero_limit_works() {
+        let mut from_id = 0;
+        let mut pages = 0;
+        loop {
+            let (items, cursor) = proposal_manager::scan_page(from_id, 4, 0, |i| Some(i));
+            assert_eq!(items, vec![from_id]);
+            pages = pages + 1;
+            match cursor {
+                Some(value) => from_id = value,
+                None => break,
+            }
+        }
+        assert_eq!(pages, 4);
+    }
+
+    /// A selective filter returns a page which is not full with the cursor after the scanned ids.
+    #[ink::test]
+    fn scan_page_scan_size_works() {
+        let scan_size = proposal_manager::MAX_SCAN_SIZE;
+        let found_id = scan_size * 2 - 1;
+        let find = |i: u128| match i == found_id {
+            true => Some(i),
+            false => None,
+        };
+        let (items, cursor) = proposal_manager::scan_page(0, scan_size * 3, 10, find);
+        assert_eq!(items.len(), 0);
+        assert_eq!(cursor, Some(scan_size));
+        let (items, cursor) = proposal_manager::scan_page(scan_size, scan_size * 3, 10, find);
+        assert_eq!(items, vec![found_id]);
+        assert_eq!(cursor, Some(scan_size * 2));
+    }
+
+    /// No option wins when every ballot is exhausted.
+    #[ink::test]
+    fn tally_instant_runoff_without_ballots_works() {
+        let ballots: Vec<(Vec<u8>, u128)> = Vec::new();
+        assert_eq!(proposal_manager::tally_instant_runoff(&ballots, 3), None);
+        let ballots: Vec<(Vec<u8>, u128)> = vec![(vec![], 1)];
+        assert_eq!(proposal_manager::tally_instant_runoff(&ballots, 2), None);
+    }
+
     // #[ink::test]
     // fn add_proposal_works() {
     //     let mut manager_contract = ManagerContract::new();